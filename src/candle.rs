@@ -0,0 +1,112 @@
+//! # Candle and Source
+//!
+//! Real feeds are OHLCV, but most indicators in `tarq` operate on a single `&[f64]` price
+//! stream. This module bridges the two: [`Candle`] is a lightweight OHLCV bar, and [`Source`]
+//! selects which price (or a derived combination of prices) to read out of it, so callers
+//! don't have to manually pre-compute a derived series like typical price before handing it
+//! to an indicator.
+
+/// A single OHLCV bar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Candle {
+    /// The bar's opening price.
+    pub open: f64,
+    /// The bar's highest price.
+    pub high: f64,
+    /// The bar's lowest price.
+    pub low: f64,
+    /// The bar's closing price.
+    pub close: f64,
+    /// The bar's traded volume.
+    pub volume: f64,
+}
+
+/// Selects which price (or derived combination of prices) to read out of a [`Candle`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// The bar's opening price.
+    Open,
+    /// The bar's highest price.
+    High,
+    /// The bar's lowest price.
+    Low,
+    /// The bar's closing price.
+    Close,
+    /// The bar's traded volume.
+    Volume,
+    /// The average of the high and low: `(H + L) / 2`.
+    HL2,
+    /// The typical price: `(H + L + C) / 3`.
+    HLC3,
+    /// The average of all four OHLC prices: `(O + H + L + C) / 4`.
+    OHLC4,
+}
+
+impl Source {
+    /// Reads the selected price (or derived combination) out of `candle`.
+    pub fn extract(&self, candle: &Candle) -> f64 {
+        match self {
+            Source::Open => candle.open,
+            Source::High => candle.high,
+            Source::Low => candle.low,
+            Source::Close => candle.close,
+            Source::Volume => candle.volume,
+            Source::HL2 => (candle.high + candle.low) / 2.0,
+            Source::HLC3 => (candle.high + candle.low + candle.close) / 3.0,
+            Source::OHLC4 => (candle.open + candle.high + candle.low + candle.close) / 4.0,
+        }
+    }
+}
+
+/// Projects a slice of [`Candle`]s into a plain `Vec<f64>` using the selected [`Source`].
+///
+/// Shared by the `from_candles` constructors (e.g.
+/// [`crate::indicators::stddev::StdDev::from_candles`],
+/// [`crate::indicators::bbpb::Bbpb::from_candles`]) so they all project sources the same way.
+pub fn project(candles: &[Candle], source: Source) -> Vec<f64> {
+    candles.iter().map(|candle| source.extract(candle)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_candle() -> Candle {
+        Candle {
+            open: 10.0,
+            high: 12.0,
+            low: 8.0,
+            close: 11.0,
+            volume: 100.0,
+        }
+    }
+
+    #[test]
+    fn test_source_extract_simple_fields() {
+        let candle = sample_candle();
+
+        assert_eq!(Source::Open.extract(&candle), 10.0);
+        assert_eq!(Source::High.extract(&candle), 12.0);
+        assert_eq!(Source::Low.extract(&candle), 8.0);
+        assert_eq!(Source::Close.extract(&candle), 11.0);
+        assert_eq!(Source::Volume.extract(&candle), 100.0);
+    }
+
+    #[test]
+    fn test_source_extract_derived_prices() {
+        let candle = sample_candle();
+
+        assert_eq!(Source::HL2.extract(&candle), 10.0);
+        assert!((Source::HLC3.extract(&candle) - (31.0 / 3.0)).abs() < 1e-9);
+        assert_eq!(Source::OHLC4.extract(&candle), 10.25);
+    }
+
+    #[test]
+    fn test_project_maps_every_candle() {
+        let candles = vec![sample_candle(), sample_candle()];
+
+        let closes = project(&candles, Source::Close);
+
+        assert_eq!(closes, vec![11.0, 11.0]);
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::collections::VecDeque;
 
+#[derive(Clone, Debug)]
 pub struct CircularBuffer<T> {
     buffer: VecDeque<T>,
     capacity: usize,
@@ -41,4 +42,14 @@ impl<T> CircularBuffer<T> {
     pub fn front(&self) -> Option<&T> {
         self.buffer.front()
     }
+
+    /// Removes every element, returning the buffer to an empty state at the same capacity.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Returns an iterator over the buffer's elements in oldest-to-newest order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.buffer.iter()
+    }
 }
@@ -45,12 +45,18 @@ use crate::indicators::{
     wma::Wma,
     dema::Dema,
     tema::Tema,
+    t3::T3,
+    hma::Hma,
+    alma::Alma,
     kama::Kama,
+    smma::Smma,
+    trima::Trima,
 };
+use crate::Streaming;
 
 /// Represents different types of moving averages available in `tarq`.
 ///
-/// This enum is primarily used in indicators that allow the user to select 
+/// This enum is primarily used in indicators that allow the user to select
 /// a specific moving average type, such as Bollinger Bands.
 #[derive(Clone, Debug)]
 pub enum MovingAverage<'a> {
@@ -66,6 +72,196 @@ pub enum MovingAverage<'a> {
     DEMA(Dema<'a>),
     /// Triple Exponential Moving Average (TEMA).
     TEMA(Tema<'a>),
+    /// Tillson T3 Moving Average.
+    T3(T3),
+    /// Hull Moving Average (HMA).
+    HMA(Hma),
+    /// Arnaud Legoux Moving Average (ALMA).
+    ALMA(Alma<'a>),
     /// Kaufman Adaptive Moving Average (KAMA).
     KAMA(Kama<'a>),
+    /// Smoothed Moving Average (SMMA / Wilder's Moving Average).
+    SMMA(Smma<'a>),
+    /// Wilder's Running Moving Average. Numerically identical to [`MovingAverage::SMMA`];
+    /// provided as its own variant since "RMA" is the name this smoothing goes by outside
+    /// of Wilder's original indicators, mirroring [`crate::indicators::atr::Smooth::Rma`].
+    RMA(Smma<'a>),
+    /// Triangular Moving Average (TRIMA).
+    TRIMA(Trima),
+}
+
+impl<'a> MovingAverage<'a> {
+    /// Drives the wrapped moving average forward by one step and returns its current value.
+    ///
+    /// This is the single dispatch interface other indicators (e.g. [`crate::indicators::bbands::BBands`])
+    /// use to advance whichever [`MovingAverage`] variant they were configured with, without
+    /// needing to match on the variant themselves.
+    ///
+    /// `sma_mean` is the already-computed SMA value for this step, reused directly for the
+    /// [`MovingAverage::SMA`] variant rather than pulling another value out of its own iterator.
+    ///
+    /// # Panics
+    /// Panics if the wrapped indicator's iterator is exhausted, mirroring how callers already
+    /// relied on `.next().unwrap()` before this method existed.
+    pub fn current(&mut self, sma_mean: f64) -> f64 {
+        match self {
+            MovingAverage::SMA(_) => sma_mean,
+            MovingAverage::EMA(ema) => ema.next().unwrap(),
+            MovingAverage::WMA(wma) => wma.next().unwrap(),
+            MovingAverage::DEMA(dema) => dema.next().unwrap(),
+            MovingAverage::TEMA(tema) => tema.next().unwrap(),
+            MovingAverage::T3(t3) => t3.next().unwrap(),
+            MovingAverage::HMA(hma) => hma.next().unwrap(),
+            MovingAverage::ALMA(alma) => alma.next().unwrap(),
+            MovingAverage::VWMA(vwma) => vwma.next().unwrap(),
+            MovingAverage::KAMA(kama) => kama.next().unwrap(),
+            MovingAverage::SMMA(smma) => smma.next().unwrap(),
+            MovingAverage::RMA(rma) => rma.next().unwrap(),
+            MovingAverage::TRIMA(trima) => trima.next().unwrap(),
+        }
+    }
+
+    /// Feeds one new sample into whichever [`MovingAverage`] variant is wrapped, returning its
+    /// updated value once the variant's own warm-up requirement has been met.
+    ///
+    /// This is the push-based counterpart to [`MovingAverage::current`]: it lets consumers
+    /// (e.g. a live-streaming [`crate::indicators::bbands::BBands`]) drive any single-price
+    /// moving average type without matching on the variant themselves.
+    ///
+    /// # Panics
+    /// Panics for [`MovingAverage::VWMA`], since it requires a paired `(price, volume)` sample
+    /// via [`crate::StreamingPriceVolume`] rather than a single price; callers that wrap VWMA
+    /// should drive it directly instead of going through this method. Also panics for
+    /// [`MovingAverage::T3`], [`MovingAverage::HMA`], [`MovingAverage::ALMA`], and
+    /// [`MovingAverage::TRIMA`], none of which implement [`crate::Streaming`]: T3, HMA, and
+    /// TRIMA fully precompute chained EMA/WMA/SMA stages, and ALMA's fixed-window Gaussian
+    /// kernel has no incremental recurrence.
+    pub fn next_value(&mut self, value: f64) -> Option<f64> {
+        match self {
+            MovingAverage::SMA(sma) => sma.update(value),
+            MovingAverage::EMA(ema) => ema.update(value),
+            MovingAverage::WMA(wma) => wma.update(value),
+            MovingAverage::DEMA(dema) => dema.update(value),
+            MovingAverage::TEMA(tema) => tema.update(value),
+            MovingAverage::KAMA(kama) => kama.update(value),
+            MovingAverage::SMMA(smma) => smma.update(value),
+            MovingAverage::RMA(rma) => rma.update(value),
+            MovingAverage::VWMA(_) => panic!(
+                "MovingAverage::VWMA requires a (price, volume) pair; drive it directly via StreamingPriceVolume instead of next_value"
+            ),
+            MovingAverage::T3(_) => panic!(
+                "MovingAverage::T3 does not support incremental updates; construct a new T3 over the full series instead"
+            ),
+            MovingAverage::HMA(_) => panic!(
+                "MovingAverage::HMA does not support incremental updates; construct a new Hma over the full series instead"
+            ),
+            MovingAverage::ALMA(_) => panic!(
+                "MovingAverage::ALMA does not support incremental updates; construct a new Alma over the full series instead"
+            ),
+            MovingAverage::TRIMA(_) => panic!(
+                "MovingAverage::TRIMA does not support incremental updates; construct a new Trima over the full series instead"
+            ),
+        }
+    }
+
+    /// Constructs a fresh [`MovingAverage`] instance of the given [`MaKind`] over `data`.
+    ///
+    /// Used by indicators such as [`crate::indicators::ribbon::Ribbon`] that need to build a
+    /// new moving average per layer from just a kind selector, rather than being handed an
+    /// already-constructed variant.
+    ///
+    /// # Errors
+    /// Propagates whatever error the underlying moving average's own constructor returns
+    /// (e.g. `period` being zero or larger than `data`).
+    pub fn from_kind(kind: MaKind, data: &'a [f64], period: usize) -> Result<Self, String> {
+        Ok(match kind {
+            MaKind::Sma => MovingAverage::SMA(Sma::new(data, period)?),
+            MaKind::Ema => MovingAverage::EMA(Ema::new(data, period)?),
+            MaKind::Wma => MovingAverage::WMA(Wma::new(data, period)?),
+            MaKind::Dema => MovingAverage::DEMA(Dema::new(data, period)?),
+            MaKind::Tema => MovingAverage::TEMA(Tema::new(data, period)?),
+            MaKind::T3 => MovingAverage::T3(T3::new(data, period, 0.7)?),
+            MaKind::Hma => MovingAverage::HMA(Hma::new(data, period)?),
+            MaKind::Alma => MovingAverage::ALMA(Alma::new(data, period, 0.85, 6.0)?),
+            MaKind::Smma => MovingAverage::SMMA(Smma::new(data, period)?),
+            MaKind::Rma => MovingAverage::RMA(Smma::new(data, period)?),
+            MaKind::Trima => MovingAverage::TRIMA(Trima::new(data, period)?),
+            MaKind::Kama => MovingAverage::KAMA(Kama::new(data, period, 2, 30)?),
+        })
+    }
+}
+
+/// Selects a moving-average *kind* without tying it to any particular data or instance.
+///
+/// Unlike [`MovingAverage`], which wraps an already-constructed indicator, `MaKind` is a
+/// lightweight, `Copy`-able selector used by indicators (like [`crate::indicators::ribbon::Ribbon`])
+/// that need to construct a fresh moving average of a chosen kind over owned, intermediate data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaKind {
+    /// Simple Moving Average (SMA).
+    Sma,
+    /// Exponential Moving Average (EMA).
+    Ema,
+    /// Weighted Moving Average (WMA).
+    Wma,
+    /// Double Exponential Moving Average (DEMA).
+    Dema,
+    /// Triple Exponential Moving Average (TEMA).
+    Tema,
+    /// Tillson T3 Moving Average, using its default volume factor (`v = 0.7`).
+    T3,
+    /// Hull Moving Average (HMA).
+    Hma,
+    /// Arnaud Legoux Moving Average (ALMA), using its default offset (`0.85`) and sigma (`6.0`).
+    Alma,
+    /// Smoothed Moving Average (SMMA / Wilder's Moving Average).
+    Smma,
+    /// Wilder's Running Moving Average (RMA). Numerically identical to [`MaKind::Smma`].
+    Rma,
+    /// Triangular Moving Average (TRIMA).
+    Trima,
+    /// Kaufman Adaptive Moving Average (KAMA), using its default fast/slow periods (2/30).
+    Kama,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::sma::Sma;
+
+    #[test]
+    fn test_moving_average_next_value_matches_streaming_variant() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let period = 3;
+
+        let mut ma = MovingAverage::SMA(Sma::new_streaming(period).unwrap());
+        let mut expected = Sma::new_streaming(period).unwrap();
+
+        for &value in &data {
+            assert_eq!(ma.next_value(value), expected.update(value));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_moving_average_next_value_panics_for_vwma() {
+        let price = vec![1.0, 2.0, 3.0];
+        let volume = vec![10.0, 10.0, 10.0];
+        let mut ma = MovingAverage::VWMA(Vwma::new(&price, &volume, 3).unwrap());
+
+        ma.next_value(4.0);
+    }
+
+    #[test]
+    fn test_moving_average_rma_matches_smma() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        let period = 3;
+
+        let mut rma = MovingAverage::RMA(Smma::new(&data, period).unwrap());
+        let mut expected = MovingAverage::SMMA(Smma::new(&data, period).unwrap());
+
+        for _ in 0..4 {
+            assert_eq!(rma.current(0.0), expected.current(0.0));
+        }
+    }
 }
@@ -0,0 +1,222 @@
+//! # Arnaud Legoux Moving Average (ALMA) Indicator
+//!
+//! The **Arnaud Legoux Moving Average (ALMA)** applies a Gaussian-shaped weighting window
+//! to each lookback period, rather than the linear weighting of
+//! [`crate::indicators::wma::Wma`]. The window's peak can be shifted toward the most recent
+//! prices, letting ALMA reduce lag while still smoothing out noise.
+//!
+//! ## Formula
+//! For a window of `period` prices, with `m = offset * (period - 1)` and `s = period / sigma`:
+//! ```text
+//! w[i]  = exp(-((i - m)^2) / (2 * s^2))       for i in 0..period
+//! ALMA  = Σ(price[i] * w[i]) / Σ(w[i])
+//! ```
+//! `offset` (typically `0.85`) shifts the Gaussian peak toward the newest samples in the
+//! window; `sigma` (typically `6`) controls how wide the Gaussian curve is.
+//!
+//! ## Performance Considerations
+//! - The weight shape is the same for every window (it only depends on a price's position
+//!   within the window, not its absolute index), so the weights are computed once in
+//!   [`Alma::new`] and reused on every step.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::alma::Alma;
+//!
+//! let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+//! let period = 5;
+//!
+//! let mut alma = Alma::new(&price_data, period, 0.85, 6.0).unwrap();
+//! let alma_values = alma.calculate().unwrap();
+//!
+//! println!("ALMA Values: {:?}", alma_values);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::Indicator;
+
+/// **The Arnaud Legoux Moving Average (ALMA) Indicator**
+///
+/// ALMA weights each price in its lookback window by a Gaussian curve whose peak can be
+/// shifted toward recent prices via `offset`, trading off lag against smoothness.
+#[derive(Clone, Debug)]
+pub struct Alma<'a> {
+    /// Reference to the input price data.
+    data: &'a [f64],
+    /// The lookback period for computing the ALMA.
+    period: usize,
+    /// Current index in the iteration process.
+    index: usize,
+    /// Precomputed Gaussian weight for each position within a window.
+    weights: Vec<f64>,
+    /// Precomputed sum of `weights`, used to normalize each window's weighted sum.
+    weight_total: f64,
+}
+
+impl<'a> Alma<'a> {
+    /// Creates a new instance of the Arnaud Legoux Moving Average (ALMA).
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `period`: The lookback period for calculating the ALMA.
+    /// - `offset`: Shifts the Gaussian peak within the window; `0.0` centers it on the
+    ///   oldest sample, `1.0` on the newest. A common default is `0.85`.
+    /// - `sigma`: Controls the width of the Gaussian curve; larger values produce a
+    ///   narrower (more lag-reducing) window. A common default is `6.0`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - The `data` length is shorter than the `period`.
+    /// - `sigma` is not greater than zero.
+    /// - `offset` is outside the `[0.0, 1.0]` range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::alma::Alma;
+    ///
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let alma = Alma::new(&price_data, 3, 0.85, 6.0);
+    ///
+    /// assert!(alma.is_ok());
+    /// ```
+    pub fn new(data: &'a [f64], period: usize, offset: f64, sigma: f64) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0.".to_string());
+        }
+        if data.len() < period {
+            return Err("Period cannot be greater than input data length.".to_string());
+        }
+        if sigma <= 0.0 {
+            return Err("Sigma must be greater than 0.".to_string());
+        }
+        if !(0.0..=1.0).contains(&offset) {
+            return Err("Offset must be between 0.0 and 1.0.".to_string());
+        }
+
+        let m = offset * (period - 1) as f64;
+        let s = period as f64 / sigma;
+
+        let weights: Vec<f64> = (0..period).map(|i| (-((i as f64 - m).powi(2)) / (2.0 * s * s)).exp()).collect();
+        let weight_total = weights.iter().sum();
+
+        Ok(Self {
+            data,
+            period,
+            index: 0,
+            weights,
+            weight_total,
+        })
+    }
+}
+
+impl Iterator for Alma<'_> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index + self.period > self.data.len() {
+            return None;
+        }
+
+        let window = &self.data[self.index..self.index + self.period];
+        let weighted_sum: f64 = window.iter().zip(self.weights.iter()).map(|(price, w)| price * w).sum();
+
+        self.index += 1;
+        Some(weighted_sum / self.weight_total)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.data.len() - self.period + 1).saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> Indicator<'a> for Alma<'a> {
+    type Output = Vec<f64>;
+
+    /// Computes the Arnaud Legoux Moving Average (ALMA) for the given data.
+    ///
+    /// Returns a vector containing the ALMA values over the dataset.
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        Ok(self.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alma_matches_manual_gaussian_window() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let period = 5;
+        let (offset, sigma) = (0.85, 6.0);
+
+        let mut alma = Alma::new(&data, period, offset, sigma).unwrap();
+        let result = alma.calculate().unwrap();
+
+        let m = offset * (period - 1) as f64;
+        let s = period as f64 / sigma;
+        let weights: Vec<f64> = (0..period).map(|i| (-((i as f64 - m).powi(2)) / (2.0 * s * s)).exp()).collect();
+        let weight_total: f64 = weights.iter().sum();
+
+        let expected: Vec<f64> = (0..=data.len() - period)
+            .map(|start| {
+                let window = &data[start..start + period];
+                window.iter().zip(weights.iter()).map(|(p, w)| p * w).sum::<f64>() / weight_total
+            })
+            .collect();
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_alma_invalid_period() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            Alma::new(&data, 0, 0.85, 6.0).is_err(),
+            "ALMA should return an error for a zero period."
+        );
+    }
+
+    #[test]
+    fn test_alma_invalid_sigma() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            Alma::new(&data, 2, 0.85, 0.0).is_err(),
+            "ALMA should return an error for a non-positive sigma."
+        );
+    }
+
+    #[test]
+    fn test_alma_invalid_offset() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            Alma::new(&data, 2, -0.1, 6.0).is_err(),
+            "ALMA should return an error for a negative offset."
+        );
+        assert!(
+            Alma::new(&data, 2, 1.1, 6.0).is_err(),
+            "ALMA should return an error for an offset above 1.0."
+        );
+    }
+
+    #[test]
+    fn test_alma_short_data() {
+        let data = vec![1.0, 2.0];
+
+        assert!(
+            Alma::new(&data, 3, 0.85, 6.0).is_err(),
+            "ALMA should return an error when data is shorter than the period."
+        );
+    }
+}
@@ -1,4 +1,96 @@
-use crate::Indicator;
+use crate::circular_buffer::CircularBuffer;
+use crate::indicators::ema::Ema;
+use crate::indicators::sma::Sma;
+use crate::{Indicator, StreamingOhlc};
+
+/// Selects how a true-range (or other) series is smoothed into an average.
+///
+/// Used by [`Atr::new_with_smooth`] and [`crate::indicators::snatr::Snatr`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Smooth {
+    /// Wilder's smoothing: `prev * (period - 1) / period + value / period`, seeded by the
+    /// SMA of the first `period` values. This is what [`Atr::new`] has always used.
+    Wilder,
+    /// Standard Exponential Moving Average smoothing (see [`crate::indicators::ema::Ema`]).
+    Ema,
+    /// Simple rolling average over the last `period` values (see [`crate::indicators::sma::Sma`]).
+    Sma,
+    /// Wilder's Running Moving Average. Numerically identical to [`Smooth::Wilder`]; provided
+    /// as its own variant since "RMA" is the name this smoothing goes by outside of ATR.
+    Rma,
+}
+
+/// Applies the selected [`Smooth`] mode to an arbitrary series.
+///
+/// Shared by [`Atr::new_with_smooth`] and [`crate::indicators::snatr::Snatr`] so both stay
+/// consistent about what each `Smooth` variant means.
+pub(crate) fn smooth_series(data: &[f64], period: usize, smooth: Smooth) -> Result<Vec<f64>, String> {
+    if period == 0 {
+        return Err("Period must be greater than zero.".to_string());
+    }
+    if data.len() < period {
+        return Err("Insufficient data for smoothing.".to_string());
+    }
+
+    match smooth {
+        Smooth::Sma => Sma::new(data, period)?.calculate(),
+        Smooth::Ema => Ema::new(data, period)?.calculate(),
+        Smooth::Wilder | Smooth::Rma => {
+            let mut result = Vec::with_capacity(data.len() - period + 1);
+            let mut prev = data[..period].iter().sum::<f64>() / period as f64;
+            result.push(prev);
+
+            for &value in &data[period..] {
+                prev *= (period - 1) as f64;
+                prev += value;
+                prev /= period as f64;
+                result.push(prev);
+            }
+
+            Ok(result)
+        }
+    }
+}
+
+/// Computes the raw (unsmoothed) True Range series for OHLC data.
+///
+/// `TR_i = max(high_i - low_i, |high_i - close_(i-1)|, |low_i - close_(i-1)|)`, for
+/// `i` in `1..high.len()` (the first bar has no previous close, so it is excluded).
+///
+/// # Errors
+/// Returns an error if:
+/// - `high`, `low`, and `close` don't all have the same length.
+/// - There are fewer than 2 bars of data.
+///
+/// # Example
+/// ```rust
+/// use tarq::indicators::atr::true_range;
+///
+/// let high = vec![2.0, 3.0, 4.0];
+/// let low = vec![1.0, 2.0, 3.0];
+/// let close = vec![1.5, 2.5, 3.5];
+///
+/// let tr = true_range(&high, &low, &close).unwrap();
+/// assert_eq!(tr.len(), 2);
+/// ```
+pub fn true_range(high: &[f64], low: &[f64], close: &[f64]) -> Result<Vec<f64>, String> {
+    if high.len() != low.len() || low.len() != close.len() {
+        return Err("All inputs must have the same length.".to_string());
+    }
+    if high.len() < 2 {
+        return Err("Insufficient data for True Range calculation.".to_string());
+    }
+
+    let mut tr = Vec::with_capacity(high.len() - 1);
+    for i in 1..high.len() {
+        let tr1 = high[i] - low[i];
+        let tr2 = (high[i] - close[i - 1]).abs();
+        let tr3 = (low[i] - close[i - 1]).abs();
+        tr.push(tr1.max(tr2).max(tr3));
+    }
+
+    Ok(tr)
+}
 
 #[derive(Clone, Debug)]
 pub struct Atr<'a> {
@@ -9,10 +101,61 @@ pub struct Atr<'a> {
     index: usize,
     previous_tr: f64,
     len: usize,
+    /// How the true-range series is smoothed into the reported ATR value.
+    smooth: Smooth,
+    /// Rolling window of raw TR values backing [`Smooth::Sma`]'s O(1) rolling mean.
+    tr_window: CircularBuffer<f64>,
+    /// Rolling sum of `tr_window`, maintained in O(1) alongside it.
+    tr_sum: f64,
+    /// The previous bar's close, needed by [`StreamingOhlc::update`] to derive TR from a
+    /// single incoming bar. `None` until the first bar has been pushed.
+    prev_close: Option<f64>,
+    /// Running sum of the samples seen while still warming up `previous_tr` via streaming.
+    warmup_sum: f64,
+    /// Number of TR values pushed through [`StreamingOhlc::update`] so far.
+    count: usize,
+    /// Whether `previous_tr` has been seeded via the warm-up window, when streaming.
+    seeded: bool,
 }
 
 impl<'a> Atr<'a> {
     pub fn new(high: &'a [f64], low: &'a [f64], close: &'a [f64], period: usize) -> Result<Self, String> {
+        Self::new_with_smooth(high, low, close, period, Smooth::Wilder)
+    }
+
+    /// Creates a new instance of the Average True Range (ATR) with a configurable
+    /// smoothing mode for the underlying true-range series.
+    ///
+    /// # Arguments
+    /// - `high`, `low`, `close`: OHLC price data (all the same length).
+    /// - `period`: The lookback period for the true-range smoothing.
+    /// - `smooth`: Which [`Smooth`] mode to apply to the true-range series.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - Any of `high`, `low`, `close` is shorter than `period`.
+    /// - `high`, `low`, and `close` don't all have the same length.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::atr::{Atr, Smooth};
+    ///
+    /// let high = vec![2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+    /// let low = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    /// let close = vec![1.5, 2.5, 3.5, 4.5, 5.5, 6.5];
+    ///
+    /// let atr = Atr::new_with_smooth(&high, &low, &close, 3, Smooth::Ema);
+    /// assert!(atr.is_ok());
+    /// ```
+    pub fn new_with_smooth(
+        high: &'a [f64],
+        low: &'a [f64],
+        close: &'a [f64],
+        period: usize,
+        smooth: Smooth,
+    ) -> Result<Self, String> {
         if period == 0 {
             return Err("Period must be greater than zero.".to_string());
         }
@@ -31,9 +174,113 @@ impl<'a> Atr<'a> {
             index: 0,
             previous_tr: 0.0,
             len: close.len(),
+            smooth,
+            tr_window: CircularBuffer::new(period),
+            tr_sum: 0.0,
+            prev_close: None,
+            warmup_sum: 0.0,
+            count: 0,
+            seeded: false,
+        })
+    }
+
+    /// Creates a streaming-only instance of the ATR with no backing slices.
+    ///
+    /// Use this constructor when bars arrive one at a time. Feed samples through
+    /// [`StreamingOhlc::update`]; [`Indicator::calculate`] will report an empty result
+    /// since there are no slices to replay.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    pub fn new_streaming(period: usize, smooth: Smooth) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than zero.".to_string());
+        }
+
+        Ok(Self {
+            high: &[],
+            low: &[],
+            close: &[],
+            period,
+            index: 0,
+            previous_tr: 0.0,
+            len: 0,
+            smooth,
+            tr_window: CircularBuffer::new(period),
+            tr_sum: 0.0,
+            prev_close: None,
+            warmup_sum: 0.0,
+            count: 0,
+            seeded: false,
         })
     }
+}
+
+impl StreamingOhlc for Atr<'_> {
+    /// Advances the ATR by exactly one OHLC bar.
+    ///
+    /// The first bar pushed only seeds `prev_close` and returns `None`, since a true
+    /// range needs a previous close. After that, returns `None` until `period` true
+    /// ranges have been seen (seeded as their SMA, matching the slice-based path), then
+    /// applies the selected [`Smooth`] recurrence on every subsequent call.
+    fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let prev_close = self.prev_close.replace(close)?;
+
+        let tr1 = high - low;
+        let tr2 = (high - prev_close).abs();
+        let tr3 = (low - prev_close).abs();
+        let tr = tr1.max(tr2).max(tr3);
+
+        if !self.seeded {
+            self.warmup_sum += tr;
+            self.count += 1;
+
+            if self.smooth == Smooth::Sma {
+                self.tr_window.push(tr);
+                self.tr_sum += tr;
+            }
+
+            if self.count < self.period {
+                return None;
+            }
 
+            self.previous_tr = self.warmup_sum / self.period as f64;
+            self.seeded = true;
+            return Some(self.previous_tr);
+        }
+
+        match self.smooth {
+            Smooth::Wilder | Smooth::Rma => {
+                self.previous_tr *= (self.period - 1) as f64;
+                self.previous_tr += tr;
+                self.previous_tr /= self.period as f64;
+            }
+            Smooth::Ema => {
+                let smoothing = 2.0 / (self.period as f64 + 1.0);
+                self.previous_tr = (tr - self.previous_tr) * smoothing + self.previous_tr;
+            }
+            Smooth::Sma => {
+                let outgoing = *self.tr_window.front().unwrap();
+                self.tr_window.push(tr);
+                self.tr_sum += tr - outgoing;
+                self.previous_tr = self.tr_sum / self.period as f64;
+            }
+        }
+
+        Some(self.previous_tr)
+    }
+
+    /// Clears the warm-up accumulator, rolling TR window, and previous close, as if
+    /// freshly constructed.
+    fn reset(&mut self) {
+        self.previous_tr = 0.0;
+        self.prev_close = None;
+        self.warmup_sum = 0.0;
+        self.count = 0;
+        self.seeded = false;
+        self.tr_window.clear();
+        self.tr_sum = 0.0;
+    }
 }
 
 impl Iterator for Atr<'_> {
@@ -53,26 +300,44 @@ impl Iterator for Atr<'_> {
                 let tr1 = self.high[i] - self.low[i];
                 let tr2 = (self.high[i] - self.close[i - 1]).abs();
                 let tr3 = (self.low[i] - self.close[i - 1]).abs();
-            
-                sum += tr1.max(tr2).max(tr3);
 
+                let tr = tr1.max(tr2).max(tr3);
+                sum += tr;
+
+                if self.smooth == Smooth::Sma {
+                    self.tr_window.push(tr);
+                    self.tr_sum += tr;
+                }
             }
             self.previous_tr = sum / self.period as f64;
             self.index += 1;
             return Some(self.previous_tr);
         }
 
-        // Calculate ATR for remaining periods using Wilders approach.
-
         let tr1 = self.high[self.index + self.period] - self.low[self.index + self.period];
         let tr2 = (self.high[self.index + self.period] - self.close[self.index + self.period - 1]).abs();
         let tr3 = (self.low[self.index + self.period] - self.close[self.index + self.period - 1]).abs();
-    
+
         let tr = tr1.max(tr2).max(tr3);
 
-        self.previous_tr *= (self.period - 1) as f64;
-        self.previous_tr += tr;
-        self.previous_tr /= self.period as f64;
+        match self.smooth {
+            Smooth::Wilder | Smooth::Rma => {
+                // Calculate ATR for remaining periods using Wilder's approach.
+                self.previous_tr *= (self.period - 1) as f64;
+                self.previous_tr += tr;
+                self.previous_tr /= self.period as f64;
+            }
+            Smooth::Ema => {
+                let smoothing = 2.0 / (self.period as f64 + 1.0);
+                self.previous_tr = (tr - self.previous_tr) * smoothing + self.previous_tr;
+            }
+            Smooth::Sma => {
+                let outgoing = *self.tr_window.front().unwrap();
+                self.tr_window.push(tr);
+                self.tr_sum += tr - outgoing;
+                self.previous_tr = self.tr_sum / self.period as f64;
+            }
+        }
 
         self.index += 1;
 
@@ -173,4 +438,106 @@ mod tests {
         assert!(atr.is_err());
         assert_eq!(atr.err().unwrap(), "Period must be greater than zero.");
     }
+
+    #[test]
+    fn test_true_range() {
+        let high = vec![2.0, 3.0, 4.0, 5.0];
+        let low = vec![1.0, 2.0, 3.0, 4.0];
+        let close = vec![1.5, 2.5, 3.5, 4.5];
+
+        let tr = true_range(&high, &low, &close).unwrap();
+
+        assert_eq!(tr, vec![1.5, 1.5, 1.5]);
+    }
+
+    #[test]
+    fn test_true_range_mismatched_lengths() {
+        let high = vec![2.0, 3.0];
+        let low = vec![1.0, 2.0, 3.0];
+        let close = vec![1.5, 2.5, 3.5];
+
+        assert!(true_range(&high, &low, &close).is_err());
+    }
+
+    #[test]
+    fn test_atr_sma_smooth_matches_rolling_mean() {
+        let high = vec![2.0, 3.5, 4.0, 5.5, 6.0, 7.5, 8.0, 9.5];
+        let low = vec![1.0, 2.5, 3.0, 4.5, 5.0, 6.5, 7.0, 8.5];
+        let close = vec![1.5, 3.0, 3.5, 5.0, 5.5, 7.0, 7.5, 9.0];
+        let period = 3;
+
+        let mut atr = Atr::new_with_smooth(&high, &low, &close, period, Smooth::Sma).unwrap();
+        let result = atr.calculate().unwrap();
+
+        let tr = true_range(&high, &low, &close).unwrap();
+        let expected: Vec<f64> = (period - 1..tr.len())
+            .map(|i| tr[i + 1 - period..=i].iter().sum::<f64>() / period as f64)
+            .collect();
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_atr_ema_smooth_matches_ema_of_tr() {
+        let high = vec![2.0, 3.5, 4.0, 5.5, 6.0, 7.5, 8.0, 9.5];
+        let low = vec![1.0, 2.5, 3.0, 4.5, 5.0, 6.5, 7.0, 8.5];
+        let close = vec![1.5, 3.0, 3.5, 5.0, 5.5, 7.0, 7.5, 9.0];
+        let period = 3;
+
+        let mut atr = Atr::new_with_smooth(&high, &low, &close, period, Smooth::Ema).unwrap();
+        let result = atr.calculate().unwrap();
+
+        let tr = true_range(&high, &low, &close).unwrap();
+        let expected = crate::indicators::ema::Ema::new(&tr, period).unwrap().calculate().unwrap();
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_atr_wilder_and_rma_are_identical() {
+        let high = vec![2.0, 3.5, 4.0, 5.5, 6.0, 7.5, 8.0, 9.5];
+        let low = vec![1.0, 2.5, 3.0, 4.5, 5.0, 6.5, 7.0, 8.5];
+        let close = vec![1.5, 3.0, 3.5, 5.0, 5.5, 7.0, 7.5, 9.0];
+        let period = 3;
+
+        let wilder = Atr::new_with_smooth(&high, &low, &close, period, Smooth::Wilder)
+            .unwrap()
+            .calculate()
+            .unwrap();
+        let rma = Atr::new_with_smooth(&high, &low, &close, period, Smooth::Rma)
+            .unwrap()
+            .calculate()
+            .unwrap();
+
+        assert_eq!(wilder, rma);
+    }
+
+    #[test]
+    fn test_atr_streaming_matches_slice_based() {
+        let high = vec![2.0, 3.5, 4.0, 5.5, 6.0, 7.5, 8.0, 9.5, 10.0, 11.5];
+        let low = vec![1.0, 2.5, 3.0, 4.5, 5.0, 6.5, 7.0, 8.5, 9.0, 10.5];
+        let close = vec![1.5, 3.0, 3.5, 5.0, 5.5, 7.0, 7.5, 9.0, 9.5, 11.0];
+        let period = 3;
+
+        let expected = Atr::new(&high, &low, &close, period).unwrap().calculate().unwrap();
+
+        let mut streaming = Atr::new_streaming(period, Smooth::Wilder).unwrap();
+        let mut streamed = Vec::new();
+        for i in 0..high.len() {
+            if let Some(value) = streaming.update(high[i], low[i], close[i]) {
+                streamed.push(value);
+            }
+        }
+
+        assert_eq!(streamed.len(), expected.len());
+        for (r, e) in streamed.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
 }
@@ -20,7 +20,14 @@
 //!   - `Lower Band = Middle Band - (Standard Deviation × Multiplier)`
 //!
 //! ## Performance Considerations
-//! - Uses a **rolling sum of squares** for an optimized standard deviation calculation.
+//! - Computing variance as `Σ(x²)/N - mean²` subtracts two large, nearly-equal quantities and
+//!   suffers catastrophic cancellation on price series with large absolute levels. Instead,
+//!   `BBands` maintains a running mean and sum of squared deviations (`M2`) using a
+//!   Welford-style sliding-window update: when a value leaves the window and another enters,
+//!   the mean and `M2` are corrected by the removal/insertion deltas rather than recomputed
+//!   from two large subtracted sums. Variance is then `M2 / (period - ddof)`, where `ddof` is
+//!   `0` for [`StdDevKind::Population`] (the default) or `1` for [`StdDevKind::Sample`],
+//!   selectable via [`BBands::with_std_dev_kind`].
 //! - Uses an **iterator-based approach**, making it efficient for streaming data analysis.
 //!
 //! ## Example Usage
@@ -43,15 +50,102 @@
 //!
 //! ## Struct Definition
 
-use crate::Indicator;
-use crate::enums::MovingAverage;
+use crate::circular_buffer::CircularBuffer;
+use crate::enums::{MaKind, MovingAverage};
 use crate::indicators::sma::Sma;
+use crate::{Indicator, StreamingBands};
+
+/// The `(upper_band, middle_band, lower_band)` triple returned by [`BBands::calculate`] and
+/// [`BBands::from_ohlc`].
+pub type BBandsOutput = (Vec<f64>, Vec<f64>, Vec<f64>);
+
+/// One row of [`BBands::calculate_with_derived`]'s output: the three bands plus the two
+/// derived series traders act on most directly, and a volatility-squeeze flag.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BBandsRow {
+    /// The upper band.
+    pub upper: f64,
+    /// The middle band.
+    pub middle: f64,
+    /// The lower band.
+    pub lower: f64,
+    /// `%b`: where price sits between the bands (`(price - lower) / (upper - lower)`).
+    pub percent_b: f64,
+    /// Bandwidth: how wide the bands are relative to the middle band (`(upper - lower) / middle`).
+    pub bandwidth: f64,
+    /// Whether this row's bandwidth is the lowest seen over the trailing `squeeze_lookback`
+    /// rows (inclusive), the usual definition of a volatility squeeze.
+    pub is_squeeze: bool,
+}
+
+/// A bar's open, high, low, and close series, grouped into a single parameter for
+/// [`BBands::from_ohlc`] rather than four parallel slices.
+#[derive(Clone, Copy, Debug)]
+pub struct OhlcSeries<'a> {
+    /// The opening price series.
+    pub open: &'a [f64],
+    /// The highest price series.
+    pub high: &'a [f64],
+    /// The lowest price series.
+    pub low: &'a [f64],
+    /// The closing price series.
+    pub close: &'a [f64],
+}
+
+/// Selects the price series Bollinger Bands are computed over, when building from raw OHLC
+/// arrays via [`BBands::from_ohlc`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceSource {
+    /// The plain closing price.
+    Close,
+    /// The median price: `(high + low) / 2`.
+    Median,
+    /// The typical price: `(high + low + close) / 3`.
+    Typical,
+    /// The weighted close: `(high + low + 2 * close) / 4`.
+    Weighted,
+    /// The average of all four OHLC prices: `(open + high + low + close) / 4`.
+    Average,
+    /// The midpoint of the bar's body: `(open + close) / 2`.
+    MedianBody,
+    /// The Heikin-Ashi close: `(open + high + low + close) / 4` computed from the raw OHLC
+    /// of each bar. (Unlike HA_Open/HA_High/HA_Low, HA_Close does not depend on the
+    /// recursive HA_Open chain, so this is equivalent to [`PriceSource::Average`] applied to
+    /// the raw bars — it is kept as its own variant to match the name traders look for.)
+    HeikinAshiClose,
+}
+
+/// Selects the divisor used when converting the rolling sum of squared deviations (`M2`) into
+/// a variance: population (`N`) or sample (`N - 1`).
+///
+/// Defaults to [`StdDevKind::Population`], matching `BBands`'s historical behavior. Set via
+/// [`BBands::with_std_dev_kind`] to match platforms that report the sample standard deviation
+/// instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StdDevKind {
+    /// Divide by `N` (the full window size).
+    Population,
+    /// Divide by `N - 1` (Bessel's correction).
+    Sample,
+}
+
+impl StdDevKind {
+    /// The degrees-of-freedom adjustment (`ddof`) for this kind: `0` for [`StdDevKind::Population`],
+    /// `1` for [`StdDevKind::Sample`].
+    pub fn ddof(&self) -> usize {
+        match self {
+            StdDevKind::Population => 0,
+            StdDevKind::Sample => 1,
+        }
+    }
+}
 
 
 /// **The Bollinger Bands Indicator**
 ///
-/// The `BBands` struct calculates Bollinger Bands using a configurable moving average 
-/// type for the middle band. It efficiently computes standard deviation using a rolling 
+/// The `BBands` struct calculates Bollinger Bands using a configurable moving average
+/// type for the middle band. It efficiently computes standard deviation using a running
+/// mean and sum of squared deviations (a Welford-style sliding update) rather than a rolling
 /// sum of squares approach.
 #[derive(Clone, Debug)]
 pub struct BBands<'a> {
@@ -61,14 +155,23 @@ pub struct BBands<'a> {
     period: usize,
     /// The standard deviation multiplier.
     std_dev: f64,
-    /// The type of moving average used for the middle band.
-    ma_type: MovingAverage<'a>,
+    /// The type of moving average used for the middle band, when driven by a slice. The
+    /// streaming variant always uses the plain rolling SMA mean instead.
+    ma_type: Option<MovingAverage<'a>>,
     /// Current index in the iteration process.
     index: usize,
-    /// Rolling sum of squared values used for standard deviation calculation.
-    rolling_sq_sum: f64,
-    /// Simple Moving Average (SMA) instance used for initial mean calculation.
-    sma: Sma<'a>,
+    /// Running mean of the values currently in the window (slice or streaming).
+    mean: f64,
+    /// Running sum of squared deviations from `mean` over the window, updated via a
+    /// Welford-style sliding-window correction rather than recomputed from a sum of squares.
+    m2: f64,
+    /// Whether variance divides by the full window size or applies Bessel's correction.
+    std_dev_kind: StdDevKind,
+    /// Simple Moving Average (SMA) instance used for initial mean calculation, when driven
+    /// by a slice.
+    sma: Option<Sma<'a>>,
+    /// Ring buffer of the last `period` values, used by the streaming variant.
+    window: CircularBuffer<f64>,
 }
 
 impl<'a> BBands<'a> {
@@ -112,49 +215,245 @@ impl<'a> BBands<'a> {
             data,
             period,
             std_dev,
-            ma_type,
+            ma_type: Some(ma_type),
             index: 0,
-            rolling_sq_sum: 0.0,
-            sma,
+            mean: 0.0,
+            m2: 0.0,
+            std_dev_kind: StdDevKind::Population,
+            sma: Some(sma),
+            window: CircularBuffer::new(period),
         })
     }
+
+    /// Overrides the default population standard deviation (divide by `N`) with the sample
+    /// convention (divide by `N - 1`), or vice versa.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::{sma::Sma, bbands::{BBands, StdDevKind}};
+    /// use tarq::enums::MovingAverage;
+    ///
+    /// let period: usize = 3;
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+    /// let bbands = BBands::new(&price_data, period, 2.0, ma_type).unwrap().with_std_dev_kind(StdDevKind::Sample);
+    /// ```
+    pub fn with_std_dev_kind(mut self, kind: StdDevKind) -> Self {
+        self.std_dev_kind = kind;
+        self
+    }
+
+    /// Creates a streaming-only instance of the Bollinger Bands with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time (e.g. from a live feed) and
+    /// the full series isn't known ahead of time. Feed samples through
+    /// [`StreamingBands::update`]; [`Indicator::calculate`] will simply report an empty
+    /// result since there is no slice to replay. Internally it owns a fixed-capacity ring
+    /// buffer of the last `period` values instead of borrowing a slice, and always uses the
+    /// plain rolling SMA for the middle band.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::StreamingBands;
+    /// use tarq::indicators::bbands::BBands;
+    ///
+    /// let mut bbands = BBands::new_streaming(3, 2.0).unwrap();
+    /// assert_eq!(bbands.update(1.0), None);
+    /// assert_eq!(bbands.update(2.0), None);
+    /// assert!(bbands.update(3.0).is_some());
+    /// ```
+    pub fn new_streaming(period: usize, std_dev: f64) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be set to a number greater than 0".to_string());
+        }
+
+        Ok(Self {
+            data: &[],
+            period,
+            std_dev,
+            ma_type: None,
+            index: 0,
+            mean: 0.0,
+            m2: 0.0,
+            std_dev_kind: StdDevKind::Population,
+            sma: None,
+            window: CircularBuffer::new(period),
+        })
+    }
+
+    /// Computes Bollinger Bands over a price series derived from raw OHLC arrays, per the
+    /// selected [`PriceSource`], instead of requiring callers to pre-transform a close series
+    /// themselves.
+    ///
+    /// Since the transformed series is only owned for the duration of this call, this
+    /// computes and returns the final bands directly rather than a [`BBands`] instance
+    /// borrowing from them (which couldn't outlive this function call).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `ohlc.open`, `ohlc.high`, and `ohlc.low` are not all the same length as `ohlc.close`.
+    /// - The `period` is zero or greater than the series length.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::enums::MaKind;
+    /// use tarq::indicators::bbands::{BBands, OhlcSeries, PriceSource};
+    ///
+    /// let ohlc = OhlcSeries {
+    ///     open: &[1.0, 2.0, 3.0, 4.0, 5.0],
+    ///     high: &[1.5, 2.5, 3.5, 4.5, 5.5],
+    ///     low: &[0.5, 1.5, 2.5, 3.5, 4.5],
+    ///     close: &[1.2, 2.2, 3.2, 4.2, 5.2],
+    /// };
+    ///
+    /// let result = BBands::from_ohlc(ohlc, 3, 2.0, MaKind::Sma, PriceSource::Typical);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn from_ohlc(
+        ohlc: OhlcSeries,
+        period: usize,
+        std_dev: f64,
+        ma_kind: MaKind,
+        source: PriceSource,
+    ) -> Result<BBandsOutput, String> {
+        let prices = Self::transform_ohlc(ohlc, source)?;
+        let ma_type = MovingAverage::from_kind(ma_kind, &prices, period)?;
+
+        BBands::new(&prices, period, std_dev, ma_type)?.calculate()
+    }
+
+    /// Projects raw OHLC arrays into a single price series per the selected [`PriceSource`].
+    fn transform_ohlc(ohlc: OhlcSeries, source: PriceSource) -> Result<Vec<f64>, String> {
+        let OhlcSeries { open, high, low, close } = ohlc;
+        let len = close.len();
+        if open.len() != len || high.len() != len || low.len() != len {
+            return Err("Open, high, low, and close series must all be the same length.".to_string());
+        }
+
+        let prices = match source {
+            PriceSource::Close => close.to_vec(),
+            PriceSource::Median => (0..len).map(|i| (high[i] + low[i]) / 2.0).collect(),
+            PriceSource::Typical => (0..len).map(|i| (high[i] + low[i] + close[i]) / 3.0).collect(),
+            PriceSource::Weighted => (0..len).map(|i| (high[i] + low[i] + 2.0 * close[i]) / 4.0).collect(),
+            PriceSource::Average | PriceSource::HeikinAshiClose => {
+                (0..len).map(|i| (open[i] + high[i] + low[i] + close[i]) / 4.0).collect()
+            }
+            PriceSource::MedianBody => (0..len).map(|i| (open[i] + close[i]) / 2.0).collect(),
+        };
+
+        Ok(prices)
+    }
 }
 
-impl Iterator for BBands<'_> {
-    type Item = (f64, f64, f64); // (upper_band, middle_band, lower_band)
+impl StreamingBands for BBands<'_> {
+    /// Advances the Bollinger Bands by exactly one sample.
+    ///
+    /// Returns `None` until `period` samples have been pushed into the ring buffer, after
+    /// which every call slides the window forward and returns `Some((upper, middle, lower))`.
+    /// The middle band is always the plain rolling SMA mean in streaming mode.
+    fn update(&mut self, value: f64) -> Option<(f64, f64, f64)> {
+        if !self.window.is_full() {
+            self.window.push(value);
 
-    fn next(&mut self) -> Option<Self::Item> {
+            if !self.window.is_full() {
+                return None;
+            }
+
+            self.mean = self.window.iter().sum::<f64>() / self.period as f64;
+            self.m2 = self.window.iter().map(|&x| (x - self.mean) * (x - self.mean)).sum();
+        } else {
+            let outgoing = *self.window.front().unwrap();
+            self.window.push(value);
+
+            let new_mean = self.mean + (value - outgoing) / self.period as f64;
+            self.m2 += (value - outgoing) * (value - new_mean + outgoing - self.mean);
+            self.mean = new_mean;
+        }
+
+        let divisor = (self.period - self.std_dev_kind.ddof()) as f64;
+        let variance = (self.m2 / divisor).max(0.0);
+        let std_dev = variance.sqrt();
+
+        let upper = self.mean + self.std_dev * std_dev;
+        let lower = self.mean - self.std_dev * std_dev;
+
+        Some((upper, self.mean, lower))
+    }
+
+    /// Clears the ring buffer, the running mean and `M2`, and the index back to the
+    /// pre-warmup state.
+    fn reset(&mut self) {
+        self.window.clear();
+        self.mean = 0.0;
+        self.m2 = 0.0;
+        self.index = 0;
+    }
+}
+
+impl BBands<'_> {
+    /// Push-one-get-one alias for [`StreamingBands::update`], named to match the
+    /// `Next`-style streaming API convention used by crates like `ta` and `bband-rs`.
+    ///
+    /// Feeds exactly one new sample into the same ring buffer and running mean/`M2` `update`
+    /// uses, returning `None` until `period` samples have been pushed, then
+    /// `Some((upper, middle, lower))` on every call after.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::indicators::bbands::BBands;
+    ///
+    /// let mut bbands = BBands::new_streaming(3, 2.0).unwrap();
+    /// assert_eq!(bbands.next_value(1.0), None);
+    /// assert_eq!(bbands.next_value(2.0), None);
+    /// assert!(bbands.next_value(3.0).is_some());
+    /// ```
+    pub fn next_value(&mut self, value: f64) -> Option<(f64, f64, f64)> {
+        self.update(value)
+    }
+}
+
+impl<'a> BBands<'a> {
+    /// Computes the next `(upper, middle, lower)` band triple from the backing slice.
+    ///
+    /// Shared by [`Iterator::next`] and by sibling indicators (e.g.
+    /// [`crate::indicators::bbpb::Bbpb`] and [`crate::indicators::bbandwidth::BBandwidth`])
+    /// that wrap a `BBands` instance and need the same band math without duplicating it.
+    pub(crate) fn next_bands(&mut self) -> Option<(f64, f64, f64)> {
         if self.index + self.period > self.data.len() {
             return None;
         }
 
         if self.index == 0 {
-            // Initialize rolling sum of squares on the first iteration
-            self.rolling_sq_sum = self.data[..self.period].iter().map(|&x| x * x).sum::<f64>();
-        } else if self.index + self.period <= self.data.len() {
-            // Efficient rolling update
+            // Initialize the running mean and M2 directly over the first window
+            let window = &self.data[..self.period];
+            self.mean = window.iter().sum::<f64>() / self.period as f64;
+            self.m2 = window.iter().map(|&x| (x - self.mean) * (x - self.mean)).sum();
+        } else {
+            // Welford-style sliding-window update: correct mean and M2 by the
+            // removal/insertion deltas instead of recomputing a sum of squares.
             let outgoing_index = self.index - 1;
             let incoming_index = self.index + self.period - 1;
+            let outgoing = self.data[outgoing_index];
+            let incoming = self.data[incoming_index];
 
-            self.rolling_sq_sum += self.data[incoming_index] * self.data[incoming_index]
-                - self.data[outgoing_index] * self.data[outgoing_index];
+            let new_mean = self.mean + (incoming - outgoing) / self.period as f64;
+            self.m2 += (incoming - outgoing) * (incoming - new_mean + outgoing - self.mean);
+            self.mean = new_mean;
         }
 
-        let mean = self.sma.next().unwrap();
+        let mean = self.sma.as_mut().unwrap().next().unwrap();
 
         // Compute the moving average using the selected ma_type for the middle band
-        let middle_band = match &mut self.ma_type {
-            MovingAverage::SMA(_) => mean, // Use stored SMA instance
-            MovingAverage::EMA(ema) => ema.next().unwrap(),
-            MovingAverage::WMA(wma) => wma.next().unwrap(),
-            MovingAverage::DEMA(dema) => dema.next().unwrap(),
-            MovingAverage::TEMA(tema) => tema.next().unwrap(),
-            MovingAverage::VWMA(vwma) => vwma.next().unwrap(),
-            MovingAverage::KAMA(kama) => kama.next().unwrap()
-        };
+        let middle_band = self.ma_type.as_mut().unwrap().current(mean);
 
         // Rolling variance calculation
-        let variance = (self.rolling_sq_sum / self.period as f64) - (mean * mean);
+        let divisor = (self.period - self.std_dev_kind.ddof()) as f64;
+        let variance = (self.m2 / divisor).max(0.0);
         let std_dev = variance.sqrt();
 
         // Compute Bollinger Bands
@@ -166,10 +465,87 @@ impl Iterator for BBands<'_> {
 
         Some((upper, middle_band, lower))
     }
+
+    /// Computes the bands together with the two derived series traders act on most directly
+    /// — %B (`(price - lower) / (upper - lower)`) and Bandwidth (`(upper - lower) / middle`)
+    /// — plus a squeeze flag, all from the single pass [`BBands::next_bands`] already drives.
+    ///
+    /// This is the single-pass alternative to separately building a
+    /// [`crate::indicators::bbpb::Bbpb`] and a [`crate::indicators::bbandwidth::BBandwidth`],
+    /// each of which would re-scan `data` from scratch to recompute the same bands. The
+    /// squeeze flag marks every bar whose Bandwidth is the lowest seen over the trailing
+    /// `squeeze_lookback` bars (inclusive) — the usual definition of a volatility squeeze,
+    /// which often precedes a breakout.
+    ///
+    /// # Errors
+    /// Returns an error if `squeeze_lookback` is zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::{sma::Sma, bbands::BBands};
+    /// use tarq::enums::MovingAverage;
+    ///
+    /// let period: usize = 3;
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+    /// let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+    /// let mut bbands = BBands::new(&price_data, period, 2.0, ma_type).unwrap();
+    ///
+    /// let rows = bbands.calculate_with_derived(2).unwrap();
+    /// let row = &rows[0];
+    /// println!("{} {} {} {} {} {}", row.upper, row.middle, row.lower, row.percent_b, row.bandwidth, row.is_squeeze);
+    /// ```
+    pub fn calculate_with_derived(&mut self, squeeze_lookback: usize) -> Result<Vec<BBandsRow>, String> {
+        if squeeze_lookback == 0 {
+            return Err("Lookback must be set to a number greater than 0".to_string());
+        }
+
+        let mut upper_band = Vec::new();
+        let mut middle_band = Vec::new();
+        let mut lower_band = Vec::new();
+        let mut percent_b = Vec::new();
+        let mut bandwidth = Vec::new();
+
+        while let Some((upper, middle, lower)) = self.next_bands() {
+            let price = self.data[self.index + self.period - 2];
+
+            upper_band.push(upper);
+            middle_band.push(middle);
+            lower_band.push(lower);
+            percent_b.push((price - lower) / (upper - lower));
+            bandwidth.push((upper - lower) / middle);
+        }
+
+        let mut rows = Vec::with_capacity(upper_band.len());
+        for i in 0..upper_band.len() {
+            let window_start = i.saturating_sub(squeeze_lookback - 1);
+            let lowest = bandwidth[window_start..=i].iter().cloned().fold(f64::INFINITY, f64::min);
+            let is_squeeze = bandwidth[i] <= lowest;
+
+            rows.push(BBandsRow {
+                upper: upper_band[i],
+                middle: middle_band[i],
+                lower: lower_band[i],
+                percent_b: percent_b[i],
+                bandwidth: bandwidth[i],
+                is_squeeze,
+            });
+        }
+
+        Ok(rows)
+    }
+}
+
+impl Iterator for BBands<'_> {
+    type Item = (f64, f64, f64); // (upper_band, middle_band, lower_band)
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_bands()
+    }
 }
 
 impl<'a> Indicator<'a> for BBands<'a> {
-    type Output = (Vec<f64>, Vec<f64>, Vec<f64>); // (upper_band, middle_band, lower_band)
+    type Output = BBandsOutput;
 
     /// Computes the Bollinger Bands for the given data.
     ///
@@ -446,5 +822,229 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bbands_streaming_matches_slice_based() {
+        let price_data = vec![
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+            8.194272150313072, 9.563328995789382,
+        ];
+        let period = 5;
+        let std_dev = 2.0;
+
+        let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+        let (expected_upper, expected_middle, expected_lower) =
+            BBands::new(&price_data, period, std_dev, ma_type).unwrap().calculate().unwrap();
+
+        let mut streaming = BBands::new_streaming(period, std_dev).unwrap();
+        let mut streamed_upper = Vec::new();
+        let mut streamed_middle = Vec::new();
+        let mut streamed_lower = Vec::new();
+        for &value in &price_data {
+            if let Some((upper, middle, lower)) = streaming.update(value) {
+                streamed_upper.push(upper);
+                streamed_middle.push(middle);
+                streamed_lower.push(lower);
+            }
+        }
+
+        assert_eq!(streamed_upper.len(), expected_upper.len());
+        for i in 0..expected_upper.len() {
+            assert!((streamed_upper[i] - expected_upper[i]).abs() < 1e-9, "Upper band mismatch at index {}", i);
+            assert!((streamed_middle[i] - expected_middle[i]).abs() < 1e-9, "Middle band mismatch at index {}", i);
+            assert!((streamed_lower[i] - expected_lower[i]).abs() < 1e-9, "Lower band mismatch at index {}", i);
+        }
+    }
+
+    #[test]
+    fn test_bbands_next_value_matches_update() {
+        let price_data = vec![
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+        ];
+        let period = 3;
+        let std_dev = 2.0;
+
+        let mut via_update = BBands::new_streaming(period, std_dev).unwrap();
+        let mut via_next_value = BBands::new_streaming(period, std_dev).unwrap();
+
+        for &value in &price_data {
+            assert_eq!(via_next_value.next_value(value), via_update.update(value));
+        }
+    }
+
+    #[test]
+    fn test_bbands_reset_clears_state() {
+        let mut streaming = BBands::new_streaming(3, 2.0).unwrap();
+        assert!(streaming.update(1.0).is_none());
+        assert!(streaming.update(2.0).is_none());
+        assert!(streaming.update(3.0).is_some());
+
+        streaming.reset();
+        assert!(streaming.update(4.0).is_none());
+        assert!(streaming.update(5.0).is_none());
+        assert!(streaming.update(6.0).is_some());
+    }
+
+    #[test]
+    fn test_bbands_with_std_dev_kind_sample_widens_bands() {
+        let price_data = vec![
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+        ];
+        let period = 4;
+        let std_dev = 2.0;
+
+        let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+        let (population_upper, middle, population_lower) =
+            BBands::new(&price_data, period, std_dev, ma_type).unwrap().calculate().unwrap();
+
+        let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+        let (sample_upper, sample_middle, sample_lower) = BBands::new(&price_data, period, std_dev, ma_type)
+            .unwrap()
+            .with_std_dev_kind(StdDevKind::Sample)
+            .calculate()
+            .unwrap();
+
+        assert_eq!(sample_middle, middle);
+        for i in 0..middle.len() {
+            assert!(sample_upper[i] >= population_upper[i], "Sample upper band should be at least as wide as population");
+            assert!(sample_lower[i] <= population_lower[i], "Sample lower band should be at least as wide as population");
+        }
+    }
+
+    #[test]
+    fn test_bbands_stable_at_large_price_levels() {
+        // A tight cluster of values sitting on a very large baseline is exactly the case
+        // that causes a naive rolling sum-of-squares accumulator to produce a tiny negative
+        // variance, whose sqrt is NaN.
+        let price_data = vec![
+            60000.01, 60000.02, 60000.03, 60000.02, 60000.01, 60000.02, 60000.03, 60000.04,
+        ];
+        let period = 4;
+        let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+
+        let (upper, middle, lower) =
+            BBands::new(&price_data, period, 2.0, ma_type).unwrap().calculate().unwrap();
+
+        for i in 0..middle.len() {
+            assert!(upper[i].is_finite() && lower[i].is_finite(), "BBands produced a non-finite band");
+            assert!(upper[i] >= middle[i] && lower[i] <= middle[i]);
+        }
+    }
+
+    #[test]
+    fn test_bbands_calculate_with_derived_matches_percent_b_and_bandwidth() {
+        let price_data = vec![
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+            8.194272150313072, 9.563328995789382, 0.7634815269862714, 12.914846107673528,
+        ];
+        let period = 5;
+        let std_dev = 2.0;
+
+        let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+        let (upper, middle, lower) = BBands::new(&price_data, period, std_dev, ma_type).unwrap().calculate().unwrap();
+
+        let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+        let mut bbands = BBands::new(&price_data, period, std_dev, ma_type).unwrap();
+        let rows = bbands.calculate_with_derived(3).unwrap();
+
+        assert_eq!(rows.len(), upper.len());
+        for (i, row) in rows.iter().enumerate() {
+            assert!((row.upper - upper[i]).abs() < 1e-9);
+            assert!((row.middle - middle[i]).abs() < 1e-9);
+            assert!((row.lower - lower[i]).abs() < 1e-9);
+
+            let price = price_data[i + period - 1];
+            let expected_percent_b = (price - lower[i]) / (upper[i] - lower[i]);
+            let expected_bandwidth = (upper[i] - lower[i]) / middle[i];
+            assert!((row.percent_b - expected_percent_b).abs() < 1e-9);
+            assert!((row.bandwidth - expected_bandwidth).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_bbands_calculate_with_derived_squeeze_flags_trailing_minimum() {
+        let price_data = vec![
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+            8.194272150313072, 9.563328995789382, 0.7634815269862714, 12.914846107673528,
+        ];
+        let period = 5;
+        let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+        let mut bbands = BBands::new(&price_data, period, 2.0, ma_type).unwrap();
+
+        let rows = bbands.calculate_with_derived(3).unwrap();
+        let bandwidths: Vec<f64> = rows.iter().map(|row| row.bandwidth).collect();
+
+        for (i, row) in rows.iter().enumerate() {
+            let window_start = i.saturating_sub(2);
+            let lowest = bandwidths[window_start..=i].iter().cloned().fold(f64::INFINITY, f64::min);
+            assert_eq!(row.is_squeeze, row.bandwidth <= lowest);
+        }
+    }
+
+    #[test]
+    fn test_bbands_calculate_with_derived_invalid_lookback() {
+        let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let period = 3;
+        let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+        let mut bbands = BBands::new(&price_data, period, 2.0, ma_type).unwrap();
+
+        assert!(bbands.calculate_with_derived(0).is_err());
+    }
+
+    #[test]
+    fn test_bbands_from_ohlc_typical_matches_manual_projection() {
+        let open = vec![9.0, 10.0, 12.0, 23.0, 23.0, 16.0];
+        let high = vec![11.0, 13.0, 24.0, 25.0, 17.0, 21.0];
+        let low = vec![8.0, 9.0, 11.0, 22.0, 15.0, 14.0];
+        let close = vec![10.0, 12.0, 23.0, 23.0, 16.0, 20.0];
+        let period = 3;
+        let std_dev = 2.0;
+
+        let typical: Vec<f64> = (0..close.len()).map(|i| (high[i] + low[i] + close[i]) / 3.0).collect();
+        let ma_type = MovingAverage::SMA(Sma::new(&typical, period).unwrap());
+        let expected = BBands::new(&typical, period, std_dev, ma_type).unwrap().calculate().unwrap();
 
+        let ohlc = OhlcSeries { open: &open, high: &high, low: &low, close: &close };
+        let result = BBands::from_ohlc(ohlc, period, std_dev, crate::enums::MaKind::Sma, PriceSource::Typical).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bbands_from_ohlc_mismatched_lengths() {
+        let open = vec![1.0, 2.0];
+        let high = vec![1.5, 2.5, 3.5];
+        let low = vec![0.5, 1.5, 2.5];
+        let close = vec![1.2, 2.2, 3.2];
+
+        let ohlc = OhlcSeries { open: &open, high: &high, low: &low, close: &close };
+        let result = BBands::from_ohlc(ohlc, 2, 2.0, crate::enums::MaKind::Sma, PriceSource::Close);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bbands_price_source_projections() {
+        let open = vec![9.0, 10.0];
+        let high = vec![11.0, 13.0];
+        let low = vec![8.0, 9.0];
+        let close = vec![10.0, 12.0];
+        let ohlc = OhlcSeries { open: &open, high: &high, low: &low, close: &close };
+
+        let median = BBands::transform_ohlc(ohlc, PriceSource::Median).unwrap();
+        assert_eq!(median, vec![9.5, 11.0]);
+
+        let weighted = BBands::transform_ohlc(ohlc, PriceSource::Weighted).unwrap();
+        assert_eq!(weighted, vec![(11.0 + 8.0 + 20.0) / 4.0, (13.0 + 9.0 + 24.0) / 4.0]);
+
+        let median_body = BBands::transform_ohlc(ohlc, PriceSource::MedianBody).unwrap();
+        assert_eq!(median_body, vec![9.5, 11.0]);
+
+        let average = BBands::transform_ohlc(ohlc, PriceSource::Average).unwrap();
+        let ha_close = BBands::transform_ohlc(ohlc, PriceSource::HeikinAshiClose).unwrap();
+        assert_eq!(average, ha_close);
+    }
 }
@@ -0,0 +1,314 @@
+//! # Bollinger Bands Signal Layer
+//!
+//! **`BBandsSignals`** is a thin mean-reversion signal adapter built on top of
+//! [`crate::indicators::bbands::BBands`]. Where `BBands` reports raw band levels and
+//! [`crate::indicators::bbpb::Bbpb`] reports where price sits between them, this module turns
+//! that same band math into discrete trade events — the kind an event-driven backtester can
+//! react to directly instead of hand-rolling crossover logic against the raw bands.
+//!
+//! ## Signals
+//! - [`BBandsSignal::EnterLong`]: price closes below the lower band (oversold).
+//! - [`BBandsSignal::EnterShort`]: price closes above the upper band (overbought).
+//! - [`BBandsSignal::Exit`]: price returns through the middle band while a position is open.
+//! - [`BBandsSignal::ScaleIn`]: price extends further beyond an already-breached band while
+//!   %B keeps moving in the same direction (add to the existing position rather than reverse).
+//!
+//! Exactly one signal, or `None`, is emitted per bar, aligned with the underlying band series.
+//!
+//! ## Performance Considerations
+//! - Uses an **iterator-based approach**, making it efficient for streaming data analysis.
+//! - **Relies on Bollinger Bands (`BBands`)** for band calculations.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::{sma::Sma, bbands_signal::BBandsSignals};
+//! use tarq::enums::MovingAverage;
+//!
+//! let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+//! let period = 3;
+//! let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+//!
+//! let mut signals = BBandsSignals::new(&price_data, period, 2.0, ma_type).unwrap();
+//! let events = signals.calculate().unwrap();
+//!
+//! println!("Bollinger Bands Signals: {:?}", events);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::enums::MovingAverage;
+use crate::indicators::bbands::BBands;
+use crate::Indicator;
+
+/// A discrete trade event derived from a bar's position relative to its Bollinger Bands.
+///
+/// Produced by [`BBandsSignals`], one (or `None`) per bar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BBandsSignal {
+    /// Price closed below the lower band with no position open: a mean-reversion long entry.
+    EnterLong,
+    /// Price closed above the upper band with no position open: a mean-reversion short entry.
+    EnterShort,
+    /// Price returned through the middle band while a position was open: close it.
+    Exit,
+    /// Price extended further beyond an already-breached band while %B kept moving in the
+    /// same direction: add to the existing position rather than reverse.
+    ScaleIn,
+}
+
+/// The signal layer's notion of which side (if any) is currently open, tracked internally so
+/// each new bar's event can be judged against it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Position {
+    Flat,
+    Long,
+    Short,
+}
+
+/// **The Bollinger Bands Signal Layer**
+///
+/// Wraps a [`BBands`] instance and turns its band levels into discrete
+/// [`BBandsSignal`] trade events, tracking the currently open position internally.
+#[derive(Clone, Debug)]
+pub struct BBandsSignals<'a> {
+    /// Reference to the input price data.
+    data: &'a [f64],
+    /// The lookback period for computing the Bollinger Bands.
+    period: usize,
+    /// Current index in the iteration process.
+    index: usize,
+    /// Bollinger Bands instance used for upper, middle, and lower band calculations.
+    bbands: BBands<'a>,
+    /// Length of the iterator when initialized.
+    len: usize,
+    /// The side currently held, if any.
+    position: Position,
+    /// The previous bar's %B value, used to detect a further extension in the same direction.
+    last_percent_b: Option<f64>,
+}
+
+impl<'a> BBandsSignals<'a> {
+    /// Creates a new instance of the Bollinger Bands signal layer.
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `period`: The lookback period for calculating Bollinger Bands.
+    /// - `std_dev`: The standard deviation multiplier.
+    /// - `ma_type`: The moving average type for the middle band.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - The `data` length is shorter than the `period`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::{sma::Sma, bbands_signal::BBandsSignals};
+    /// use tarq::enums::MovingAverage;
+    ///
+    /// let period = 3;
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+    /// let signals = BBandsSignals::new(&price_data, period, 2.0, ma_type);
+    ///
+    /// assert!(signals.is_ok());
+    /// ```
+    pub fn new(data: &'a [f64], period: usize, std_dev: f64, ma_type: MovingAverage<'a>) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be set to a number greater than 0".to_string());
+        }
+        if data.len() < period {
+            return Err("Period cannot be greater than input data length.".to_string());
+        }
+
+        let bbands = BBands::new(data, period, std_dev, ma_type)?;
+
+        Ok(Self {
+            data,
+            period,
+            index: 0,
+            bbands,
+            len: data.len(),
+            position: Position::Flat,
+            last_percent_b: None,
+        })
+    }
+
+    /// Classifies one bar against the currently open position, updating `self.position` and
+    /// `self.last_percent_b` in the process.
+    fn signal_for(&mut self, price: f64, upper: f64, middle: f64, lower: f64) -> Option<BBandsSignal> {
+        let percent_b = (price - lower) / (upper - lower);
+        let last_percent_b = self.last_percent_b.replace(percent_b);
+
+        match self.position {
+            Position::Flat => {
+                if price < lower {
+                    self.position = Position::Long;
+                    Some(BBandsSignal::EnterLong)
+                } else if price > upper {
+                    self.position = Position::Short;
+                    Some(BBandsSignal::EnterShort)
+                } else {
+                    None
+                }
+            }
+            Position::Long => {
+                if price >= middle {
+                    self.position = Position::Flat;
+                    Some(BBandsSignal::Exit)
+                } else if price < lower && last_percent_b.is_some_and(|last| percent_b < last) {
+                    Some(BBandsSignal::ScaleIn)
+                } else {
+                    None
+                }
+            }
+            Position::Short => {
+                if price <= middle {
+                    self.position = Position::Flat;
+                    Some(BBandsSignal::Exit)
+                } else if price > upper && last_percent_b.is_some_and(|last| percent_b > last) {
+                    Some(BBandsSignal::ScaleIn)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for BBandsSignals<'_> {
+    type Item = Option<BBandsSignal>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (upper, middle, lower) = self.bbands.next_bands()?;
+        let price = self.data[self.index + self.period - 1];
+
+        self.index += 1;
+        Some(self.signal_for(price, upper, middle, lower))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len.saturating_sub(self.period + self.index) + 1;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> Indicator<'a> for BBandsSignals<'a> {
+    type Output = Vec<Option<BBandsSignal>>;
+
+    /// Computes one [`BBandsSignal`] (or `None`) per bar, aligned with the underlying band
+    /// series.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::{sma::Sma, bbands_signal::BBandsSignals};
+    /// use tarq::enums::MovingAverage;
+    ///
+    /// let period = 3;
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+    /// let mut signals = BBandsSignals::new(&price_data, period, 2.0, ma_type).unwrap();
+    ///
+    /// let events = signals.calculate().unwrap();
+    /// println!("Bollinger Bands Signals: {:?}", events);
+    /// ```
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        let mut result = Vec::with_capacity(self.len);
+        result.extend(self);
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::MovingAverage;
+    use crate::indicators::sma::Sma;
+
+    #[test]
+    fn test_bbands_signals_enters_long_below_lower_band() {
+        let data = vec![10.0, 10.1, 9.9, 10.0, 10.05, 8.0];
+        let period = 5;
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let mut signals = BBandsSignals::new(&data, period, 1.0, ma_type).unwrap();
+
+        let events = signals.calculate().unwrap();
+
+        assert_eq!(events.last().copied().unwrap(), Some(BBandsSignal::EnterLong));
+    }
+
+    #[test]
+    fn test_bbands_signals_enters_short_above_upper_band() {
+        let data = vec![10.0, 10.1, 9.9, 10.0, 10.05, 12.0];
+        let period = 5;
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let mut signals = BBandsSignals::new(&data, period, 1.0, ma_type).unwrap();
+
+        let events = signals.calculate().unwrap();
+
+        assert_eq!(events.last().copied().unwrap(), Some(BBandsSignal::EnterShort));
+    }
+
+    #[test]
+    fn test_bbands_signals_exits_on_middle_band_cross() {
+        // A long, flat history keeps the bands tight, so the 9.5/9.0/7.0 decline breaches
+        // and extends the lower band before the final bar crosses back through the middle.
+        let mut data = vec![10.0; 28];
+        data.extend([9.5, 9.0, 7.0, 11.0]);
+        let period = 30;
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let mut signals = BBandsSignals::new(&data, period, 0.5, ma_type).unwrap();
+
+        let events = signals.calculate().unwrap();
+
+        assert_eq!(events[events.len() - 3], Some(BBandsSignal::EnterLong));
+        assert_eq!(events[events.len() - 2], Some(BBandsSignal::ScaleIn));
+        assert_eq!(events[events.len() - 1], Some(BBandsSignal::Exit));
+    }
+
+    #[test]
+    fn test_bbands_signals_scales_in_on_further_extension() {
+        let mut data = vec![10.0; 28];
+        data.extend([9.5, 9.0, 7.0]);
+        let period = 30;
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let mut signals = BBandsSignals::new(&data, period, 0.5, ma_type).unwrap();
+
+        let events = signals.calculate().unwrap();
+
+        assert_eq!(events[events.len() - 2], Some(BBandsSignal::EnterLong));
+        assert_eq!(events[events.len() - 1], Some(BBandsSignal::ScaleIn));
+    }
+
+    #[test]
+    fn test_bbands_signals_no_event_inside_bands() {
+        let data = vec![10.0, 10.1, 9.9, 10.0, 10.05];
+        let period = 5;
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let mut signals = BBandsSignals::new(&data, period, 2.0, ma_type).unwrap();
+
+        let events = signals.calculate().unwrap();
+
+        assert_eq!(events, vec![None]);
+    }
+
+    #[test]
+    fn test_bbands_signals_invalid_input() {
+        let data = vec![];
+        let result = BBandsSignals::new(&data, 5, 2.0, MovingAverage::SMA(Sma::new(&data, 5).unwrap_or_else(|_| Sma::new(&[0.0], 1).unwrap())));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bbands_signals_short_data() {
+        let data = vec![1.0, 2.0, 3.0, 4.0];
+        let result = BBandsSignals::new(&data, 5, 2.0, MovingAverage::SMA(Sma::new(&data, 5).unwrap_or_else(|_| Sma::new(&[0.0], 1).unwrap())));
+
+        assert!(result.is_err());
+    }
+}
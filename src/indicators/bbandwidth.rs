@@ -0,0 +1,286 @@
+//! # Bollinger Bandwidth Indicator
+//!
+//! The **Bollinger Bandwidth (BBandwidth)** indicator measures how wide the Bollinger Bands
+//! are relative to the middle band, expressing volatility as a single normalized value. It is
+//! the standard sibling of [`crate::indicators::bbpb::Bbpb`] (%b): where %b locates price
+//! within the bands, Bandwidth tracks how far apart the bands themselves are, which is the
+//! usual way volatility squeezes are detected ahead of a breakout.
+//!
+//! ## Formula
+//! The bandwidth value is computed as:
+//! ```text
+//! Bandwidth = (Upper Band - Lower Band) / Middle Band
+//! ```
+//!
+//! ## Interpretation
+//! - A **falling** bandwidth indicates the bands are contracting (low volatility, a potential
+//!   squeeze ahead of a breakout).
+//! - A **rising** bandwidth indicates the bands are expanding (high volatility).
+//!
+//! ## Performance Considerations
+//! - Uses a **rolling iterator-based approach**, making it efficient for streaming data analysis.
+//! - **Relies on Bollinger Bands (`BBands`)** for band calculations.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::{sma::Sma, bbands::BBands, bbandwidth::BBandwidth};
+//! use tarq::enums::MovingAverage;
+//!
+//! let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+//! let period = 3;
+//! let std_dev_mul = 2.0;
+//! let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+//!
+//! let mut bandwidth = BBandwidth::new(&price_data, period, std_dev_mul, ma_type).unwrap();
+//!
+//! let values = bandwidth.calculate().unwrap();
+//!
+//! println!("Bollinger Bandwidth Values: {:?}", values);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::enums::MovingAverage;
+use crate::indicators::bbands::BBands;
+use crate::Indicator;
+
+/// **The Bollinger Bandwidth Indicator**
+///
+/// The `BBandwidth` struct calculates the Bollinger Bandwidth value, which measures how wide
+/// the Bollinger Bands are relative to the middle band.
+///
+/// This indicator is useful for detecting volatility squeezes ahead of a breakout.
+///
+/// It internally relies on the [`BBands`] struct for Bollinger Band calculations.
+#[derive(Clone, Debug)]
+pub struct BBandwidth<'a> {
+    /// The lookback period for computing the Bollinger Bands.
+    period: usize,
+    /// Current index in the iteration process.
+    index: usize,
+    /// Bollinger Bands instance used for upper, middle, and lower band calculations.
+    bbands: BBands<'a>,
+    /// Length of the iterator when initialized.
+    len: usize,
+}
+
+impl<'a> BBandwidth<'a> {
+    /// Creates a new instance of the Bollinger Bandwidth (BBandwidth) indicator.
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `period`: The lookback period for calculating Bollinger Bands.
+    /// - `std_dev`: The standard deviation multiplier.
+    /// - `ma_type`: The moving average type for the middle band.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - The `data` length is shorter than the `period`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::{sma::Sma, bbandwidth::BBandwidth};
+    /// use tarq::enums::MovingAverage;
+    ///
+    /// let period = 3;
+    /// let std_dev_mul = 2.0;
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+    /// let bandwidth = BBandwidth::new(&price_data, period, std_dev_mul, ma_type);
+    ///
+    /// assert!(bandwidth.is_ok());
+    /// ```
+    pub fn new(data: &'a [f64], period: usize, std_dev: f64, ma_type: MovingAverage<'a>) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be set to a number greater than 0".to_string());
+        }
+        if data.len() < period {
+            return Err("Period cannot be greater than input data length.".to_string());
+        }
+
+        let bbands = BBands::new(data, period, std_dev, ma_type)?;
+
+        Ok(Self {
+            period,
+            index: 0,
+            bbands,
+            len: data.len(),
+        })
+    }
+
+    /// Computes the Bollinger Bandwidth values for the given data, paired with a squeeze flag
+    /// marking every point whose bandwidth is the lowest seen over the trailing `lookback`
+    /// values (inclusive of the point itself) — the usual definition of a volatility squeeze.
+    ///
+    /// # Errors
+    /// Returns an error if `lookback` is zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::{sma::Sma, bbandwidth::BBandwidth};
+    /// use tarq::enums::MovingAverage;
+    ///
+    /// let period = 3;
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+    /// let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+    /// let mut bandwidth = BBandwidth::new(&price_data, period, 2.0, ma_type).unwrap();
+    ///
+    /// let squeezes = bandwidth.calculate_with_squeeze(3).unwrap();
+    /// println!("Bollinger Bandwidth Squeezes: {:?}", squeezes);
+    /// ```
+    pub fn calculate_with_squeeze(&mut self, lookback: usize) -> Result<Vec<(f64, bool)>, String> {
+        if lookback == 0 {
+            return Err("Lookback must be set to a number greater than 0".to_string());
+        }
+
+        let values = self.calculate()?;
+        let mut result = Vec::with_capacity(values.len());
+
+        for (i, &value) in values.iter().enumerate() {
+            let window_start = i.saturating_sub(lookback - 1);
+            let lowest = values[window_start..=i].iter().cloned().fold(f64::INFINITY, f64::min);
+            result.push((value, value <= lowest));
+        }
+
+        Ok(result)
+    }
+}
+
+impl Iterator for BBandwidth<'_> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (upper, middle, lower) = self.bbands.next_bands()?;
+
+        self.index += 1;
+        Some((upper - lower) / middle)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len.saturating_sub(self.period + self.index) + 1;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> Indicator<'a> for BBandwidth<'a> {
+    type Output = Vec<f64>;
+
+    /// Computes the Bollinger Bandwidth values for the given data.
+    ///
+    /// Returns a vector containing the bandwidth values over the dataset.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::{sma::Sma, bbandwidth::BBandwidth};
+    /// use tarq::enums::MovingAverage;
+    ///
+    /// let period = 3;
+    /// let std_dev_mul = 2.0;
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+    /// let mut bandwidth = BBandwidth::new(&price_data, period, std_dev_mul, ma_type).unwrap();
+    ///
+    /// let values = bandwidth.calculate().unwrap();
+    ///
+    /// println!("Bollinger Bandwidth Values: {:?}", values);
+    /// ```
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        let mut result = Vec::with_capacity(self.len);
+        result.extend(self);
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::MovingAverage;
+    use crate::indicators::sma::Sma;
+
+    #[test]
+    fn test_bbandwidth_matches_manual_band_ratio() {
+        let data = vec![
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+            8.194272150313072, 9.563328995789382, 0.7634815269862714, 12.914846107673528,
+        ];
+        let period = 5;
+        let std_dev = 2.0;
+
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let (upper, middle, lower) = BBands::new(&data, period, std_dev, ma_type).unwrap().calculate().unwrap();
+        let expected: Vec<f64> = upper.iter().zip(middle.iter()).zip(lower.iter())
+            .map(|((u, m), l)| (u - l) / m)
+            .collect();
+
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let mut bandwidth = BBandwidth::new(&data, period, std_dev, ma_type).unwrap();
+        let result = bandwidth.calculate().unwrap();
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_bbandwidth_invalid_input() {
+        let data = vec![];
+
+        let result = BBandwidth::new(&data, 5, 2.0, MovingAverage::SMA(Sma::new(&data, 5).unwrap_or_else(|_| Sma::new(&[0.0], 1).unwrap())));
+
+        assert!(result.is_err(), "BBandwidth should return an error for empty input.");
+    }
+
+    #[test]
+    fn test_bbandwidth_short_data() {
+        let data = vec![1.0, 2.0, 3.0, 4.0];
+
+        let result = BBandwidth::new(&data, 5, 2.0, MovingAverage::SMA(Sma::new(&data, 5).unwrap_or_else(|_| Sma::new(&[0.0], 1).unwrap())));
+
+        assert!(result.is_err(), "BBandwidth should return an error when data is shorter than the period.");
+    }
+
+    #[test]
+    fn test_bbandwidth_squeeze_flags_trailing_minimum() {
+        let data = vec![
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+            8.194272150313072, 9.563328995789382, 0.7634815269862714, 12.914846107673528,
+        ];
+        let period = 5;
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let mut bandwidth = BBandwidth::new(&data, period, 2.0, ma_type).unwrap();
+
+        let values = bandwidth.calculate().unwrap();
+
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let mut bandwidth = BBandwidth::new(&data, period, 2.0, ma_type).unwrap();
+        let squeezes = bandwidth.calculate_with_squeeze(3).unwrap();
+
+        assert_eq!(squeezes.len(), values.len());
+        for (i, (value, flagged)) in squeezes.iter().enumerate() {
+            assert_eq!(*value, values[i]);
+
+            let window_start = i.saturating_sub(2);
+            let lowest = values[window_start..=i].iter().cloned().fold(f64::INFINITY, f64::min);
+            assert_eq!(*flagged, values[i] <= lowest);
+        }
+    }
+
+    #[test]
+    fn test_bbandwidth_calculate_with_squeeze_invalid_lookback() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let period = 3;
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let mut bandwidth = BBandwidth::new(&data, period, 2.0, ma_type).unwrap();
+
+        assert!(bandwidth.calculate_with_squeeze(0).is_err());
+    }
+}
@@ -43,9 +43,10 @@
 //!
 //! ## Struct Definition
 
-use crate::Indicator;
-use crate::enums::MovingAverage;
+use crate::candle::{project, Candle, Source};
+use crate::enums::{MaKind, MovingAverage};
 use crate::indicators::bbands::BBands;
+use crate::{Indicator, Streaming, StreamingBands};
 
 /// **The Bollinger Bands %b (Percent Bandwidth) Indicator**
 ///
@@ -68,6 +69,38 @@ pub struct Bbpb<'a> {
     bbands: BBands<'a>,
     /// Length of the iterator when initialized.
     len: usize,
+    /// The %b level at or below which a value is considered oversold. Defaults to `0.0`.
+    lower_threshold: f64,
+    /// The %b level at or above which a value is considered overbought. Defaults to `1.0`.
+    upper_threshold: f64,
+}
+
+/// A classification of where a [`Bbpb`] value sits relative to its configured thresholds.
+///
+/// Produced by [`Bbpb::calculate_with_signals`] alongside the raw %b float, so strategy
+/// code can react to band-piercing without re-deriving it from the float stream.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BbpbSignal {
+    /// The %b value is at or above the upper threshold (classically `>= 1.0`, i.e. price
+    /// at or above the upper Bollinger Band).
+    AboveUpper,
+    /// The %b value is at or below the lower threshold (classically `<= 0.0`, i.e. price
+    /// at or below the lower Bollinger Band).
+    BelowLower,
+    /// The %b value sits strictly between the two thresholds.
+    Neutral,
+}
+
+impl BbpbSignal {
+    /// Returns `true` if this signal is [`BbpbSignal::AboveUpper`] (overbought).
+    pub fn is_overbought(&self) -> bool {
+        matches!(self, BbpbSignal::AboveUpper)
+    }
+
+    /// Returns `true` if this signal is [`BbpbSignal::BelowLower`] (oversold).
+    pub fn is_oversold(&self) -> bool {
+        matches!(self, BbpbSignal::BelowLower)
+    }
 }
 
 impl<'a> Bbpb<'a> {
@@ -114,8 +147,157 @@ impl<'a> Bbpb<'a> {
             index: 0,
             bbands,
             len: data.len(),
+            lower_threshold: 0.0,
+            upper_threshold: 1.0,
         })
     }
+
+    /// Overrides the default overbought/oversold thresholds (`0.0`/`1.0`) used by
+    /// [`Bbpb::calculate_with_signals`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::{sma::Sma, bbpb::Bbpb};
+    /// use tarq::enums::MovingAverage;
+    ///
+    /// let period = 3;
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+    /// let bbpb = Bbpb::new(&price_data, period, 2.0, ma_type).unwrap().with_thresholds(0.1, 0.9);
+    /// ```
+    pub fn with_thresholds(mut self, lower: f64, upper: f64) -> Self {
+        self.lower_threshold = lower;
+        self.upper_threshold = upper;
+        self
+    }
+
+    /// Classifies a %b value against the configured thresholds.
+    fn signal_for(&self, value: f64) -> BbpbSignal {
+        if value >= self.upper_threshold {
+            BbpbSignal::AboveUpper
+        } else if value <= self.lower_threshold {
+            BbpbSignal::BelowLower
+        } else {
+            BbpbSignal::Neutral
+        }
+    }
+
+    /// Computes the Bollinger %b values for the given data, paired with a [`BbpbSignal`]
+    /// classifying each value against the configured (or default) thresholds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::{sma::Sma, bbpb::Bbpb};
+    /// use tarq::enums::MovingAverage;
+    ///
+    /// let period = 3;
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+    /// let ma_type = MovingAverage::SMA(Sma::new(&price_data, period).unwrap());
+    /// let mut bbpb = Bbpb::new(&price_data, period, 2.0, ma_type).unwrap();
+    ///
+    /// let signals = bbpb.calculate_with_signals().unwrap();
+    /// println!("Bollinger %b Signals: {:?}", signals);
+    /// ```
+    pub fn calculate_with_signals(&mut self) -> Result<Vec<(f64, BbpbSignal)>, String> {
+        let values = self.calculate()?;
+        Ok(values.into_iter().map(|value| (value, self.signal_for(value))).collect())
+    }
+
+    /// Creates a streaming-only instance of the Bollinger %b with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time (e.g. from a live feed) and
+    /// the full series isn't known ahead of time. Feed samples through
+    /// [`Streaming::update`]; [`Indicator::calculate`] will simply report an empty result
+    /// since there is no slice to replay. Internally it drives a streaming [`BBands`], which
+    /// always uses the plain rolling SMA for the middle band.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::Streaming;
+    /// use tarq::indicators::bbpb::Bbpb;
+    ///
+    /// let mut bbpb = Bbpb::new_streaming(3, 2.0).unwrap();
+    /// assert_eq!(bbpb.update(1.0), None);
+    /// assert_eq!(bbpb.update(2.0), None);
+    /// assert!(bbpb.update(3.0).is_some());
+    /// ```
+    pub fn new_streaming(period: usize, std_dev: f64) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be set to a number greater than 0".to_string());
+        }
+
+        let bbands = BBands::new_streaming(period, std_dev)?;
+
+        Ok(Self {
+            data: &[],
+            period,
+            index: 0,
+            bbands,
+            len: 0,
+            lower_threshold: 0.0,
+            upper_threshold: 1.0,
+        })
+    }
+
+    /// Computes the Bollinger %b values for a chosen [`Source`] projected out of a slice of
+    /// OHLCV [`Candle`]s, e.g. typical price (`Source::HLC3`) instead of a plain close.
+    ///
+    /// Since the projected prices are only owned for the duration of this call, this
+    /// computes and returns the final %b values directly rather than a [`Bbpb`] instance
+    /// borrowing from them (which couldn't outlive this function call).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - `candles` is shorter than the `period`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::candle::{Candle, Source};
+    /// use tarq::enums::MaKind;
+    /// use tarq::indicators::bbpb::Bbpb;
+    ///
+    /// let candles = vec![
+    ///     Candle { open: 1.0, high: 2.0, low: 0.5, close: 1.5, volume: 100.0 },
+    ///     Candle { open: 1.5, high: 2.5, low: 1.0, close: 2.0, volume: 120.0 },
+    ///     Candle { open: 2.0, high: 3.0, low: 1.5, close: 2.5, volume: 90.0 },
+    /// ];
+    ///
+    /// let result = Bbpb::from_candles(&candles, 3, 2.0, MaKind::Sma, Source::HLC3);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn from_candles(
+        candles: &[Candle],
+        period: usize,
+        std_dev: f64,
+        ma_kind: MaKind,
+        source: Source,
+    ) -> Result<Vec<f64>, String> {
+        let prices = project(candles, source);
+        let ma_type = MovingAverage::from_kind(ma_kind, &prices, period)?;
+        Bbpb::new(&prices, period, std_dev, ma_type)?.calculate()
+    }
+}
+
+impl Streaming for Bbpb<'_> {
+    /// Advances the %b value by exactly one sample.
+    ///
+    /// Returns `None` until the underlying [`BBands`] has collected `period` samples,
+    /// after which every call returns `Some` with the latest %b value.
+    fn update(&mut self, value: f64) -> Option<f64> {
+        let (upper, _, lower) = self.bbands.update(value)?;
+        Some((value - lower) / (upper - lower))
+    }
+
+    /// Resets the underlying streaming [`BBands`] back to its pre-warmup state.
+    fn reset(&mut self) {
+        self.bbands.reset();
+    }
 }
 
 impl Iterator for Bbpb<'_> {
@@ -127,7 +309,7 @@ impl Iterator for Bbpb<'_> {
         }
 
         // Get the Bollinger Bands values
-        let (upperband, _, lowerband) = self.bbands.next().unwrap();
+        let (upperband, _, lowerband) = self.bbands.next_bands().unwrap();
 
         // Compute %b using the latest price and Bollinger Bands
         let bandwith = (self.data[self.index + self.period - 1] - lowerband) / (upperband - lowerband);
@@ -214,4 +396,119 @@ mod tests {
             assert!((actual - exp).abs() < 1e-6, "Value at index {} differs: expected {}, got {}", i, exp, actual);
         }
     }
+
+    #[test]
+    fn test_bbpb_streaming_matches_slice_based() {
+        let data = vec![
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+            8.194272150313072, 9.563328995789382,
+        ];
+        let period = 5;
+        let std_dev = 2.0;
+
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let expected = Bbpb::new(&data, period, std_dev, ma_type).unwrap().calculate().unwrap();
+
+        let mut streaming = Bbpb::new_streaming(period, std_dev).unwrap();
+        let streamed: Vec<f64> = data.iter().filter_map(|&value| streaming.update(value)).collect();
+
+        assert_eq!(streamed.len(), expected.len());
+        for (r, e) in streamed.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_bbpb_from_candles_matches_projected_source() {
+        let candles = vec![
+            Candle { open: 9.0, high: 11.0, low: 8.0, close: 10.0, volume: 100.0 },
+            Candle { open: 10.0, high: 13.0, low: 9.0, close: 12.0, volume: 110.0 },
+            Candle { open: 12.0, high: 24.0, low: 11.0, close: 23.0, volume: 90.0 },
+            Candle { open: 23.0, high: 25.0, low: 22.0, close: 23.0, volume: 95.0 },
+            Candle { open: 23.0, high: 17.0, low: 15.0, close: 16.0, volume: 80.0 },
+            Candle { open: 16.0, high: 21.0, low: 14.0, close: 20.0, volume: 85.0 },
+        ];
+        let period = 3;
+        let std_dev = 2.0;
+
+        let expected_prices: Vec<f64> = candles.iter().map(|c| (c.high + c.low + c.close) / 3.0).collect();
+        let ma_type = MovingAverage::SMA(Sma::new(&expected_prices, period).unwrap());
+        let expected = Bbpb::new(&expected_prices, period, std_dev, ma_type).unwrap().calculate().unwrap();
+
+        let result = Bbpb::from_candles(&candles, period, std_dev, MaKind::Sma, Source::HLC3).unwrap();
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_bbpb_from_candles_short_data() {
+        let candles = vec![Candle { open: 1.0, high: 2.0, low: 0.5, close: 1.5, volume: 10.0 }];
+
+        assert!(Bbpb::from_candles(&candles, 3, 2.0, MaKind::Sma, Source::Close).is_err());
+    }
+
+    #[test]
+    fn test_bbpb_reset_clears_state() {
+        let mut streaming = Bbpb::new_streaming(3, 2.0).unwrap();
+        assert!(streaming.update(1.0).is_none());
+        assert!(streaming.update(2.0).is_none());
+        assert!(streaming.update(3.0).is_some());
+
+        streaming.reset();
+        assert!(streaming.update(4.0).is_none());
+        assert!(streaming.update(5.0).is_none());
+        assert!(streaming.update(6.0).is_some());
+    }
+
+    #[test]
+    fn test_bbpb_calculate_with_signals_default_thresholds() {
+        let data = vec![
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+            8.194272150313072, 9.563328995789382, 0.7634815269862714, 12.914846107673528,
+        ];
+        let period = 5;
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+
+        let mut bbpb = Bbpb::new(&data, period, 2.0, ma_type).unwrap();
+        let values = bbpb.calculate().unwrap();
+
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+        let mut bbpb = Bbpb::new(&data, period, 2.0, ma_type).unwrap();
+        let signals = bbpb.calculate_with_signals().unwrap();
+
+        assert_eq!(signals.len(), values.len());
+        for ((value, signal), expected_value) in signals.iter().zip(values.iter()) {
+            assert_eq!(value, expected_value);
+
+            let expected_signal = if *value >= 1.0 {
+                BbpbSignal::AboveUpper
+            } else if *value <= 0.0 {
+                BbpbSignal::BelowLower
+            } else {
+                BbpbSignal::Neutral
+            };
+            assert_eq!(*signal, expected_signal);
+        }
+    }
+
+    #[test]
+    fn test_bbpb_with_thresholds_narrows_neutral_zone() {
+        let data = vec![
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+            8.194272150313072, 9.563328995789382,
+        ];
+        let period = 5;
+        let ma_type = MovingAverage::SMA(Sma::new(&data, period).unwrap());
+
+        let mut bbpb = Bbpb::new(&data, period, 2.0, ma_type).unwrap().with_thresholds(0.3, 0.7);
+        let signals = bbpb.calculate_with_signals().unwrap();
+
+        assert!(signals.iter().any(|(_, signal)| signal.is_overbought() || signal.is_oversold()));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,338 @@
+//! # Brownian Bands Indicator
+//!
+//! The **Brownian Bands** indicator is a volatility-band construction that is an alternative
+//! to [`crate::indicators::bbands::BBands`]. Rather than a single fixed `period`, it treats
+//! price as a Brownian motion: over a lookback of `k` bars the expected spread of a random
+//! walk scales with `sqrt(k)`, so the half-width of the band at lookback `k` is
+//! `σ_k * sqrt(k)`, where `σ_k` is the standard deviation of the `k` most recent one-bar
+//! returns. The bands are centered on the current price rather than a moving average.
+//!
+//! ## Why not plain standard-deviation bands
+//! Fixed-period standard-deviation bands tend to cluster almost all returns between the
+//! bands, leaving the tails under-represented. Averaging the Brownian half-width over every
+//! lookback `1..=N` (rather than picking one fixed `N`) spreads the band estimate across
+//! short- and long-horizon volatility regimes at once, giving a cleaner three-way split of
+//! returns into "above", "between", and "below" the bands.
+//!
+//! ## Calculation
+//! For each bar, and for every lookback `k` in `1..=max_lookback` for which `k` returns are
+//! already available:
+//! - `σ_k` = standard deviation of the `k` most recent one-bar returns (via [`StdDev`]).
+//! - `half_width_k = σ_k * sqrt(k)`.
+//! - `upper_k = price + half_width_k`, `lower_k = price - half_width_k`.
+//!
+//! The emitted upper/lower bands are the mean of `upper_k`/`lower_k` over every available
+//! `k`; the mid band is simply the current price, since every `k` centers on it.
+//!
+//! ## Adaptive lookback
+//! [`BrownianBands::with_periods`] accepts a per-bar lookback (e.g. a dominant-cycle
+//! estimate) in place of the `1..=max_lookback` average: at each bar it uses only the `σ_k`
+//! for that bar's requested `k` (clamped to `max_lookback`) instead of averaging across all
+//! of them.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::brownian_bands::BrownianBands;
+//!
+//! let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+//! let max_lookback = 3;
+//!
+//! let mut bands = BrownianBands::new(&price_data, max_lookback).unwrap();
+//! let (upper, mid, lower) = bands.calculate().unwrap();
+//!
+//! println!("Upper Band: {:?}", upper);
+//! println!("Mid Band: {:?}", mid);
+//! println!("Lower Band: {:?}", lower);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::indicators::stddev::StdDev;
+use crate::{Indicator, Streaming};
+
+/// **The Brownian Bands Indicator**
+///
+/// The `BrownianBands` struct computes volatility bands centered on price using a
+/// Brownian-motion half-width averaged across every lookback from `1` to `max_lookback`
+/// (or, with [`BrownianBands::with_periods`], a single adaptive lookback per bar).
+#[derive(Clone, Debug)]
+pub struct BrownianBands<'a> {
+    /// Reference to the input price data.
+    data: &'a [f64],
+    /// The maximum lookback length `N` averaged (or adaptively selected) per bar.
+    max_lookback: usize,
+    /// An optional per-bar lookback (e.g. a dominant-cycle estimate) that replaces the
+    /// `1..=max_lookback` average with a single adaptively chosen `k`.
+    periods: Option<&'a [usize]>,
+    /// Current index into the return series (`data[index]` -> `data[index + 1]`).
+    index: usize,
+    /// One streaming [`StdDev`] per lookback `k` in `1..=max_lookback`, each tracking the
+    /// standard deviation of the `k` most recent one-bar returns.
+    return_stddevs: Vec<StdDev<'static>>,
+}
+
+impl<'a> BrownianBands<'a> {
+    /// Creates a new instance of the Brownian Bands indicator.
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `max_lookback`: The maximum lookback length `N` averaged over per bar.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `max_lookback` is zero.
+    /// - `data` has fewer than 2 points (at least one return is required).
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::brownian_bands::BrownianBands;
+    ///
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let bands = BrownianBands::new(&price_data, 3);
+    ///
+    /// assert!(bands.is_ok());
+    /// ```
+    pub fn new(data: &'a [f64], max_lookback: usize) -> Result<Self, String> {
+        if max_lookback == 0 {
+            return Err("Max lookback must be set to a number greater than 0".to_string());
+        }
+        if data.len() < 2 {
+            return Err("At least two data points are required to compute a return".to_string());
+        }
+
+        let return_stddevs = (1..=max_lookback)
+            .map(|k| StdDev::new_streaming(k, 0))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            data,
+            max_lookback,
+            periods: None,
+            index: 0,
+            return_stddevs,
+        })
+    }
+
+    /// Replaces the `1..=max_lookback` averaging with a single adaptive lookback per bar,
+    /// e.g. a dominant-cycle estimate computed upstream.
+    ///
+    /// `periods` must have the same length as the input data; `periods[i]` is clamped to
+    /// `[1, max_lookback]` and selects the single `σ_k` used to build the band at bar `i`.
+    ///
+    /// # Errors
+    /// Returns an error if `periods` is not the same length as the input data.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::brownian_bands::BrownianBands;
+    ///
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let periods = vec![1, 1, 2, 2, 3];
+    /// let bands = BrownianBands::new(&price_data, 3).unwrap().with_periods(&periods);
+    ///
+    /// assert!(bands.is_ok());
+    /// ```
+    pub fn with_periods(mut self, periods: &'a [usize]) -> Result<Self, String> {
+        if periods.len() != self.data.len() {
+            return Err("Periods must be the same length as the input data".to_string());
+        }
+
+        self.periods = Some(periods);
+        Ok(self)
+    }
+}
+
+impl Iterator for BrownianBands<'_> {
+    type Item = (f64, f64, f64); // (upper_band, mid_band, lower_band)
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index + 1 >= self.data.len() {
+            return None;
+        }
+
+        let price = self.data[self.index + 1];
+        let ret = price - self.data[self.index];
+
+        let mut upper_sum = 0.0;
+        let mut lower_sum = 0.0;
+        let mut count = 0usize;
+
+        if let Some(periods) = self.periods {
+            let target_k = periods[self.index + 1].clamp(1, self.max_lookback);
+
+            for (i, stddev) in self.return_stddevs.iter_mut().enumerate() {
+                let k = i + 1;
+                let sigma = stddev.update(ret);
+
+                if k == target_k {
+                    if let Some(sigma) = sigma {
+                        let half_width = sigma * (k as f64).sqrt();
+                        upper_sum = price + half_width;
+                        lower_sum = price - half_width;
+                        count = 1;
+                    }
+                }
+            }
+        } else {
+            for (i, stddev) in self.return_stddevs.iter_mut().enumerate() {
+                let k = i + 1;
+
+                if let Some(sigma) = stddev.update(ret) {
+                    let half_width = sigma * (k as f64).sqrt();
+                    upper_sum += price + half_width;
+                    lower_sum += price - half_width;
+                    count += 1;
+                }
+            }
+        }
+
+        self.index += 1;
+
+        if count == 0 {
+            return Some((price, price, price));
+        }
+
+        let upper = upper_sum / count as f64;
+        let lower = lower_sum / count as f64;
+
+        Some((upper, price, lower))
+    }
+}
+
+impl<'a> Indicator<'a> for BrownianBands<'a> {
+    type Output = (Vec<f64>, Vec<f64>, Vec<f64>); // (upper_band, mid_band, lower_band)
+
+    /// Computes the Brownian Bands for the given data.
+    ///
+    /// Returns three vectors representing the upper, mid, and lower bands.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::brownian_bands::BrownianBands;
+    ///
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let mut bands = BrownianBands::new(&price_data, 3).unwrap();
+    ///
+    /// let (upper, mid, lower) = bands.calculate().unwrap();
+    /// println!("Upper Band: {:?}", upper);
+    /// println!("Mid Band: {:?}", mid);
+    /// println!("Lower Band: {:?}", lower);
+    /// ```
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        let len = self.data.len() - 1;
+
+        let mut upper_band = Vec::with_capacity(len);
+        let mut mid_band = Vec::with_capacity(len);
+        let mut lower_band = Vec::with_capacity(len);
+
+        self.by_ref().for_each(|(upper, mid, lower)| {
+            upper_band.push(upper);
+            mid_band.push(mid);
+            lower_band.push(lower);
+        });
+
+        Ok((upper_band, mid_band, lower_band))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brownian_bands_mid_is_current_price() {
+        let data = vec![10.0, 12.0, 23.0, 23.0, 16.0, 20.0, 25.0, 30.0, 28.0, 26.0];
+        let mut bands = BrownianBands::new(&data, 3).unwrap();
+
+        let (_, mid, _) = bands.calculate().unwrap();
+
+        assert_eq!(mid, &data[1..]);
+    }
+
+    #[test]
+    fn test_brownian_bands_bounds_straddle_price() {
+        let data = vec![10.0, 12.0, 23.0, 23.0, 16.0, 20.0, 25.0, 30.0, 28.0, 26.0];
+        let mut bands = BrownianBands::new(&data, 3).unwrap();
+
+        let (upper, mid, lower) = bands.calculate().unwrap();
+
+        for i in 0..mid.len() {
+            assert!(upper[i] >= mid[i], "Upper band should be at or above price at index {}", i);
+            assert!(lower[i] <= mid[i], "Lower band should be at or below price at index {}", i);
+        }
+    }
+
+    #[test]
+    fn test_brownian_bands_first_point_uses_only_k_equals_one() {
+        // With only one return available, only k = 1 contributes, and the standard
+        // deviation of a single-sample window is 0, so the bands collapse onto the price.
+        let data = vec![10.0, 12.0, 23.0, 23.0];
+        let mut bands = BrownianBands::new(&data, 3).unwrap();
+
+        let (upper, mid, lower) = bands.calculate().unwrap();
+
+        assert!((upper[0] - mid[0]).abs() < 1e-9);
+        assert!((lower[0] - mid[0]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_brownian_bands_invalid_max_lookback() {
+        let data = vec![1.0, 2.0, 3.0];
+        assert!(BrownianBands::new(&data, 0).is_err());
+    }
+
+    #[test]
+    fn test_brownian_bands_too_short_data() {
+        let data = vec![1.0];
+        assert!(BrownianBands::new(&data, 3).is_err());
+    }
+
+    #[test]
+    fn test_brownian_bands_with_periods_matches_single_lookback() {
+        let data = vec![10.0, 12.0, 23.0, 23.0, 16.0, 20.0, 25.0, 30.0, 28.0, 26.0];
+        let periods = vec![2; data.len()];
+
+        let mut bands = BrownianBands::new(&data, 4).unwrap().with_periods(&periods).unwrap();
+        let (upper, mid, lower) = bands.calculate().unwrap();
+
+        let mut reference_stddev = StdDev::new_streaming(2, 0).unwrap();
+        let mut expected_upper = Vec::new();
+        let mut expected_lower = Vec::new();
+        for i in 0..data.len() - 1 {
+            let ret = data[i + 1] - data[i];
+            let sigma = reference_stddev.update(ret);
+            let price = data[i + 1];
+            match sigma {
+                Some(sigma) => {
+                    let half_width = sigma * (2.0f64).sqrt();
+                    expected_upper.push(price + half_width);
+                    expected_lower.push(price - half_width);
+                }
+                None => {
+                    expected_upper.push(price);
+                    expected_lower.push(price);
+                }
+            }
+        }
+
+        assert_eq!(upper.len(), expected_upper.len());
+        for i in 0..upper.len() {
+            assert!((upper[i] - expected_upper[i]).abs() < 1e-9, "Upper band mismatch at index {}", i);
+            assert!((lower[i] - expected_lower[i]).abs() < 1e-9, "Lower band mismatch at index {}", i);
+            assert_eq!(mid[i], data[i + 1]);
+        }
+    }
+
+    #[test]
+    fn test_brownian_bands_with_periods_invalid_length() {
+        let data = vec![1.0, 2.0, 3.0];
+        let periods = vec![1, 2];
+
+        let bands = BrownianBands::new(&data, 2).unwrap().with_periods(&periods);
+        assert!(bands.is_err());
+    }
+}
@@ -0,0 +1,234 @@
+//! # Crossover (Cross) Signal Detector
+//!
+//! **Cross** turns two moving-average-style series into actionable entry/exit markers by
+//! flagging the exact index where one line crosses the other. This is the common trigger
+//! behind crossover strategies (e.g. a fast VWMA crossing a slow EMA, or an EMA crossing an
+//! SMA).
+//!
+//! ## Signal
+//! For each index `i` (after the first), comparing series `a` against series `b`:
+//! ```text
+//!  +1  if a[i-1] <= b[i-1] and a[i] > b[i]   (a crosses above b)
+//!  -1  if a[i-1] >= b[i-1] and a[i] < b[i]   (a crosses below b)
+//!   0  otherwise
+//! ```
+//! Index `0` always reports `0`, since a cross needs a previous sample to compare against.
+//!
+//! ## Alignment
+//! `a` and `b` often come from indicators with different warm-up lengths (e.g. a 5-period
+//! EMA and a 20-period SMA), so [`Cross::new`] aligns them on their common tail, trimming
+//! the longer series down to the length of the shorter one before comparing.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::cross::Cross;
+//! use tarq::indicators::ema::Ema;
+//! use tarq::indicators::sma::Sma;
+//!
+//! let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+//!
+//! let mut ema = Ema::new(&price_data, 3).unwrap();
+//! let mut sma = Sma::new(&price_data, 5).unwrap();
+//!
+//! let mut cross = Cross::from_indicators(&mut ema, &mut sma).unwrap();
+//! let signals = cross.calculate().unwrap();
+//!
+//! println!("Cross Signals: {:?}", signals);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::Indicator;
+
+/// **The Crossover (Cross) Signal Detector**
+///
+/// Cross compares two aligned series index-by-index and reports `+1` where the first
+/// crosses above the second, `-1` where it crosses below, and `0` otherwise.
+#[derive(Clone, Debug)]
+pub struct Cross {
+    /// The precomputed signal values, aligned to the common tail of the input series.
+    values: Vec<i8>,
+    /// Current index in the iteration process.
+    index: usize,
+}
+
+impl Cross {
+    /// Creates a new instance of the Crossover (Cross) signal detector from two raw series.
+    ///
+    /// # Arguments
+    /// - `a`: The first series (e.g. the fast line).
+    /// - `b`: The second series (e.g. the slow line).
+    ///
+    /// If `a` and `b` differ in length, both are trimmed to their common tail (the last
+    /// `a.len().min(b.len())` values) before comparing, since the leading warm-up offsets
+    /// of differently-configured indicators aren't directly comparable.
+    ///
+    /// # Errors
+    /// Returns an error if either `a` or `b` is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::indicators::cross::Cross;
+    ///
+    /// let a = vec![1.0, 2.0, 3.0, 4.0];
+    /// let b = vec![2.0, 2.0, 2.0, 2.0];
+    /// let cross = Cross::new(&a, &b);
+    ///
+    /// assert!(cross.is_ok());
+    /// ```
+    pub fn new(a: &[f64], b: &[f64]) -> Result<Self, String> {
+        if a.is_empty() || b.is_empty() {
+            return Err("Both series must be non-empty.".to_string());
+        }
+
+        let len = a.len().min(b.len());
+        let a = &a[a.len() - len..];
+        let b = &b[b.len() - len..];
+
+        let mut values = Vec::with_capacity(len);
+        values.push(0);
+
+        for i in 1..len {
+            let crossed_above = a[i - 1] <= b[i - 1] && a[i] > b[i];
+            let crossed_below = a[i - 1] >= b[i - 1] && a[i] < b[i];
+
+            values.push(if crossed_above {
+                1
+            } else if crossed_below {
+                -1
+            } else {
+                0
+            });
+        }
+
+        Ok(Self { values, index: 0 })
+    }
+
+    /// Creates a new instance of the Crossover (Cross) signal detector directly from two
+    /// indicators, running `calculate()` on both before comparing.
+    ///
+    /// # Arguments
+    /// - `a`: The first indicator (e.g. the fast line).
+    /// - `b`: The second indicator (e.g. the slow line).
+    ///
+    /// # Errors
+    /// Returns an error if either indicator's `calculate()` fails, or if either output is
+    /// empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::cross::Cross;
+    /// use tarq::indicators::ema::Ema;
+    /// use tarq::indicators::sma::Sma;
+    ///
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+    /// let mut ema = Ema::new(&price_data, 3).unwrap();
+    /// let mut sma = Sma::new(&price_data, 5).unwrap();
+    ///
+    /// let cross = Cross::from_indicators(&mut ema, &mut sma);
+    /// assert!(cross.is_ok());
+    /// ```
+    pub fn from_indicators<'a, A, B>(a: &mut A, b: &mut B) -> Result<Self, String>
+    where
+        A: Indicator<'a, Output = Vec<f64>>,
+        B: Indicator<'a, Output = Vec<f64>>,
+    {
+        let a_values = a.calculate()?;
+        let b_values = b.calculate()?;
+        Self::new(&a_values, &b_values)
+    }
+}
+
+impl Iterator for Cross {
+    type Item = i8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.values.get(self.index).copied()?;
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.values.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> Indicator<'a> for Cross {
+    type Output = Vec<i8>;
+
+    /// Computes the crossover signal series for the given inputs.
+    ///
+    /// Returns a vector of `+1` (crossed above), `-1` (crossed below), and `0` (no cross)
+    /// values, one per aligned index.
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        Ok(self.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ema::Ema;
+    use crate::indicators::sma::Sma;
+
+    #[test]
+    fn test_cross_detects_above_and_below() {
+        let a = vec![1.0, 3.0, 1.0, 3.0, 1.0];
+        let b = vec![2.0, 2.0, 2.0, 2.0, 2.0];
+
+        let mut cross = Cross::new(&a, &b).unwrap();
+        let result = cross.calculate().unwrap();
+
+        assert_eq!(result, vec![0, 1, -1, 1, -1]);
+    }
+
+    #[test]
+    fn test_cross_aligns_unequal_length_series() {
+        // `a` has two extra leading samples that should be trimmed away before comparing.
+        let a = vec![0.0, 0.0, 1.0, 3.0, 1.0];
+        let b = vec![2.0, 2.0, 2.0];
+
+        let mut cross = Cross::new(&a, &b).unwrap();
+        let result = cross.calculate().unwrap();
+
+        assert_eq!(result, vec![0, 1, -1]);
+    }
+
+    #[test]
+    fn test_cross_no_crossings_is_all_zero() {
+        let a = vec![1.0, 1.1, 1.2, 1.3];
+        let b = vec![2.0, 2.1, 2.2, 2.3];
+
+        let mut cross = Cross::new(&a, &b).unwrap();
+        let result = cross.calculate().unwrap();
+
+        assert_eq!(result, vec![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_cross_invalid_empty_input() {
+        let a: Vec<f64> = vec![];
+        let b = vec![1.0, 2.0];
+
+        assert!(Cross::new(&a, &b).is_err(), "Cross should return an error for an empty series.");
+    }
+
+    #[test]
+    fn test_cross_from_indicators() {
+        let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 9.0, 8.0, 7.0, 6.0, 5.0];
+
+        let mut ema = Ema::new(&price_data, 3).unwrap();
+        let mut sma = Sma::new(&price_data, 5).unwrap();
+
+        let ema_values = Ema::new(&price_data, 3).unwrap().calculate().unwrap();
+        let sma_values = Sma::new(&price_data, 5).unwrap().calculate().unwrap();
+        let expected = Cross::new(&ema_values, &sma_values).unwrap().calculate().unwrap();
+
+        let result = Cross::from_indicators(&mut ema, &mut sma).unwrap().calculate().unwrap();
+
+        assert_eq!(result, expected);
+    }
+}
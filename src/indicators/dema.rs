@@ -38,11 +38,11 @@
 //!
 //! ## Struct Definition
 
-use crate::Indicator;
+use crate::{Indicator, Streaming};
 
 /// **The Double Exponential Moving Average (DEMA) Indicator**
 ///
-/// DEMA is a modified Exponential Moving Average (EMA) that smooths the price series 
+/// DEMA is a modified Exponential Moving Average (EMA) that smooths the price series
 /// while reducing lag. It calculates two EMAs and applies the formula:
 ///
 /// `DEMA = 2 * EMA1 - EMA2`
@@ -60,6 +60,10 @@ pub struct Dema<'a> {
     prev_ema2: f64,
     /// The smoothing factor used in the EMA formula.
     smoothing: f64,
+    /// Samples collected while warming up `prev_ema1`/`prev_ema2`.
+    warmup: Vec<f64>,
+    /// Whether `prev_ema1`/`prev_ema2` have been seeded via the warm-up window.
+    seeded: bool,
 }
 
 impl<'a> Dema<'a> {
@@ -101,52 +105,78 @@ impl<'a> Dema<'a> {
             prev_ema1: 0.0,
             prev_ema2: 0.0,
             smoothing: 2.0 / (period as f64 + 1.0),
+            warmup: Vec::with_capacity(2 * period - 1),
+            seeded: false,
+        })
+    }
+
+    /// Creates a streaming-only instance of the DEMA with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time and the full series
+    /// isn't known ahead of time. Feed samples through [`Streaming::update`];
+    /// [`Indicator::calculate`] will report an empty result since there is no slice to replay.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    pub fn new_streaming(period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0".to_string());
+        }
+
+        Ok(Self {
+            data: &[],
+            index: 0,
+            period,
+            prev_ema1: 0.0,
+            prev_ema2: 0.0,
+            smoothing: 2.0 / (period as f64 + 1.0),
+            warmup: Vec::with_capacity(2 * period - 1),
+            seeded: false,
         })
     }
 }
 
-impl Iterator for Dema<'_> {
-    type Item = f64;
+impl Streaming for Dema<'_> {
+    /// Advances the DEMA by exactly one sample.
+    ///
+    /// Returns `None` until `2 * period - 1` samples have been pushed, which is the
+    /// same warm-up window the slice-based path uses to seed `prev_ema1`/`prev_ema2`.
+    /// After that, every call applies the two EMA recurrences and returns
+    /// `Some(2 * ema1 - ema2)`.
+    fn update(&mut self, value: f64) -> Option<f64> {
+        if !self.seeded {
+            self.warmup.push(value);
+            let warmup_len = 2 * self.period - 1;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index + (2 * self.period - 2) >= self.data.len() {
-            return None;
-        }
+            if self.warmup.len() < warmup_len {
+                return None;
+            }
 
-        if self.index == 0 {
             // Step 1: Compute the first EMA1 as SMA of the first `period` values
-            let sum: f64 = self.data[..self.period].iter().sum();
+            let sum: f64 = self.warmup[..self.period].iter().sum();
             self.prev_ema1 = sum / self.period as f64;
 
             // Step 2: Collect `period` EMA1 values
             let mut ema1_values = Vec::with_capacity(self.period);
             ema1_values.push(self.prev_ema1);
 
-            for i in self.period..(2 * self.period - 1) {
-                let ema1 = (self.data[i] - self.prev_ema1) * self.smoothing + self.prev_ema1;
-
-                self.prev_ema1 = ema1;
-                ema1_values.push(ema1);
+            for &v in &self.warmup[self.period..warmup_len] {
+                self.prev_ema1 = (v - self.prev_ema1) * self.smoothing + self.prev_ema1;
+                ema1_values.push(self.prev_ema1);
             }
 
             // Step 3: Compute the first EMA2 as SMA of `period` EMA1 values
             let sum_ema1: f64 = ema1_values.iter().sum();
             self.prev_ema2 = sum_ema1 / self.period as f64;
 
-            // Step 4: Compute the first DEMA value
-            let dema = 2.0 * self.prev_ema1 - self.prev_ema2;
-            self.index += 1;
-            return Some(dema);
-        }
-
-        // Offset index to start after initialization phase
-        let price_index = self.index + (2 * self.period - 2);
-
+            self.seeded = true;
+            self.warmup = Vec::new();
 
-        let price = self.data[price_index];
+            return Some(2.0 * self.prev_ema1 - self.prev_ema2);
+        }
 
         // Compute EMA1
-        self.prev_ema1 = (price - self.prev_ema1) * self.smoothing + self.prev_ema1;
+        self.prev_ema1 = (value - self.prev_ema1) * self.smoothing + self.prev_ema1;
 
         // Compute EMA2
         self.prev_ema2 = (self.prev_ema1 - self.prev_ema2) * self.smoothing + self.prev_ema2;
@@ -154,11 +184,34 @@ impl Iterator for Dema<'_> {
         // Compute DEMA
         let dema = 2.0 * self.prev_ema1 - self.prev_ema2;
 
-        self.index += 1;
         Some(dema)
     }
+
+    /// Clears the warm-up buffer and both EMA accumulators, as if freshly constructed.
+    fn reset(&mut self) {
+        self.prev_ema1 = 0.0;
+        self.prev_ema2 = 0.0;
+        self.warmup = Vec::with_capacity(2 * self.period - 1);
+        self.seeded = false;
+    }
 }
 
+impl Iterator for Dema<'_> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.data.len() {
+            let value = self.data[self.index];
+            self.index += 1;
+
+            if let Some(result) = self.update(value) {
+                return Some(result);
+            }
+        }
+
+        None
+    }
+}
 
 impl<'a> Indicator<'a> for Dema<'a> {
     type Output = Vec<f64>;
@@ -225,4 +278,24 @@ mod tests {
             assert!((r - e).abs() < 1e-6, "Expected {}, got {}", e, r);
         }
     }
+
+    #[test]
+    fn test_dema_streaming_matches_slice_based() {
+        let data = [
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+            8.194272150313072, 9.563328995789382, 0.7634815269862714, 12.914846107673528,
+        ];
+        let period = 5;
+
+        let expected = Dema::new(&data, period).unwrap().calculate().unwrap();
+
+        let mut streaming = Dema::new_streaming(period).unwrap();
+        let streamed: Vec<f64> = data.iter().filter_map(|&value| streaming.update(value)).collect();
+
+        assert_eq!(streamed.len(), expected.len());
+        for (r, e) in streamed.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-6, "Expected {}, got {}", e, r);
+        }
+    }
 }
@@ -41,12 +41,12 @@
 //!
 //! ## Struct Definition
 
-use crate::Indicator;
+use crate::{Indicator, Streaming};
 
 /// **The Exponential Moving Average (EMA) Indicator**
 ///
-/// EMA is a moving average that gives higher weight to recent prices, making it 
-/// more responsive to price changes than a Simple Moving Average (SMA). It is commonly 
+/// EMA is a moving average that gives higher weight to recent prices, making it
+/// more responsive to price changes than a Simple Moving Average (SMA). It is commonly
 /// used in trading strategies to detect trends.
 #[derive(Clone, Debug)]
 pub struct Ema<'a> {
@@ -60,6 +60,10 @@ pub struct Ema<'a> {
     prev_ema: f64,
     /// The smoothing factor used in the EMA formula.
     smoothing: f64,
+    /// Running sum of the samples seen while still warming up.
+    warmup_sum: f64,
+    /// Number of samples pushed through [`Streaming::update`] so far.
+    count: usize,
 }
 
 impl<'a> Ema<'a> {
@@ -98,30 +102,90 @@ impl<'a> Ema<'a> {
             index: 0,
             prev_ema: 0.0,
             smoothing: 2.0 / (period as f64 + 1.0),
+            warmup_sum: 0.0,
+            count: 0,
+        })
+    }
+
+    /// Creates a streaming-only instance of the EMA with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time (e.g. from a live feed)
+    /// and the full series isn't known ahead of time. Feed samples through
+    /// [`Streaming::update`]; [`Indicator::calculate`] will simply report an empty result
+    /// since there is no slice to replay.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::Streaming;
+    /// use tarq::indicators::ema::Ema;
+    ///
+    /// let mut ema = Ema::new_streaming(3).unwrap();
+    /// assert_eq!(ema.update(1.0), None);
+    /// assert_eq!(ema.update(2.0), None);
+    /// assert!(ema.update(3.0).is_some());
+    /// ```
+    pub fn new_streaming(period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0".to_string());
+        }
+
+        Ok(Self {
+            data: &[],
+            period,
+            index: 0,
+            prev_ema: 0.0,
+            smoothing: 2.0 / (period as f64 + 1.0),
+            warmup_sum: 0.0,
+            count: 0,
         })
     }
 }
 
-impl Iterator for Ema<'_> {
-    type Item = f64;
+impl Streaming for Ema<'_> {
+    /// Advances the EMA by exactly one sample.
+    ///
+    /// Returns `None` until `period` samples have been pushed, at which point the
+    /// seed value is the simple average of the warm-up window. Every subsequent call
+    /// applies the EMA recurrence and returns `Some`.
+    fn update(&mut self, value: f64) -> Option<f64> {
+        if self.count < self.period {
+            self.warmup_sum += value;
+            self.count += 1;
+
+            if self.count == self.period {
+                self.prev_ema = self.warmup_sum / self.period as f64;
+                return Some(self.prev_ema);
+            }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index + self.period > self.data.len() {
             return None;
         }
 
-        if self.index == 0 {
-            // Calculate initial EMA as the SMA of the first `period` values
-            self.prev_ema = self.data[..self.period].iter().sum::<f64>() / self.period as f64;
-            self.index += 1;
-            return Some(self.prev_ema);
-        }
+        self.prev_ema = (value - self.prev_ema) * self.smoothing + self.prev_ema;
+        Some(self.prev_ema)
+    }
+
+    /// Clears the warm-up accumulator and `prev_ema`, as if freshly constructed.
+    fn reset(&mut self) {
+        self.prev_ema = 0.0;
+        self.warmup_sum = 0.0;
+        self.count = 0;
+    }
+}
+
+impl Iterator for Ema<'_> {
+    type Item = f64;
 
-        if self.index < self.data.len() {
-            // Apply the EMA formula
-            self.prev_ema = (self.data[self.index + self.period - 1] - self.prev_ema) * self.smoothing + self.prev_ema;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.data.len() {
+            let value = self.data[self.index];
             self.index += 1;
-            return Some(self.prev_ema);
+
+            if let Some(result) = self.update(value) {
+                return Some(result);
+            }
         }
 
         None
@@ -202,4 +266,23 @@ mod tests {
             "EMA should return an error when data is shorter than the period."
         );
     }
+
+    #[test]
+    fn test_ema_streaming_matches_slice_based() {
+        let input_data = vec![
+            5.2, 12.6, 9.8, 8.1, 2.4, 2.5, 1.2, 11.5, 8.1, 9.5,
+            0.7, 12.9, 11.1, 3.2, 2.8, 2.8, 4.3, 7.2, 6.0, 4.2,
+        ];
+        let period = 5;
+
+        let expected = Ema::new(&input_data, period).unwrap().calculate().unwrap();
+
+        let mut streaming = Ema::new_streaming(period).unwrap();
+        let streamed: Vec<f64> = input_data
+            .iter()
+            .filter_map(|&value| streaming.update(value))
+            .collect();
+
+        assert_eq!(streamed, expected, "Streaming EMA should match the slice-based calculation.");
+    }
 }
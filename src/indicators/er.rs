@@ -0,0 +1,295 @@
+//! # Kaufman Efficiency Ratio (ER) Indicator
+//!
+//! The **Efficiency Ratio (ER)**, introduced by Perry Kaufman as the first step of
+//! [`crate::indicators::kama::Kama`], measures how efficiently price moves relative to the
+//! total distance it travelled over a lookback window. It is useful on its own as a
+//! trend-strength / choppiness filter: values near `1` indicate a clean trend, values near
+//! `0` indicate noisy, directionless movement.
+//!
+//! ## Formula
+//! ```text
+//! ER_t = |Price_t - Price_(t-period)| / Σ_(i=t-period+1..=t) |Price_i - Price_(i-1)|
+//! ```
+//! The numerator is the net price change over the window; the denominator is the sum of
+//! the absolute bar-to-bar price changes over the same window (the total distance travelled).
+//! When the denominator is `0` (e.g. a flat price series), ER is defined as `0.0`.
+//!
+//! ## Performance Considerations
+//! - Maintains the denominator as a rolling sum via a [`CircularBuffer`], subtracting the
+//!   oldest absolute change and adding the newest one on every step, the same O(1) approach
+//!   [`crate::indicators::kama::Kama`] uses internally.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::er::Er;
+//!
+//! let price_data = vec![
+//!     5.29, 12.66, 9.86, 8.16, 2.49, 2.49, 1.24, 11.58,
+//!     8.19, 9.56, 0.76, 12.91, 11.15, 3.21, 2.82,
+//! ];
+//! let period = 5;
+//!
+//! let mut er = Er::new(&price_data, period).unwrap();
+//! let er_values = er.calculate().unwrap();
+//!
+//! println!("ER Values: {:?}", er_values);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::circular_buffer::CircularBuffer;
+use crate::{Indicator, Streaming};
+
+/// **The Kaufman Efficiency Ratio (ER) Indicator**
+///
+/// ER measures the fraction of a window's total price movement that went toward net
+/// directional change, ranging from `0` (choppy/noisy) to `1` (clean trend).
+#[derive(Clone, Debug)]
+pub struct Er<'a> {
+    /// Reference to the input price data.
+    data: &'a [f64],
+    /// The lookback period over which ER is computed.
+    period: usize,
+    /// Current index in the iteration process.
+    index: usize,
+    /// Rolling sum of absolute price changes, maintained in O(1) via `window`.
+    sum_roc: f64,
+    /// The last price pushed, used to extend `sum_roc` by one term per update.
+    last_price: f64,
+    /// Sliding window of the last `period + 1` prices backing the rolling `sum_roc`.
+    window: CircularBuffer<f64>,
+    /// Whether the warm-up window has produced the first ER value yet.
+    seeded: bool,
+}
+
+impl<'a> Er<'a> {
+    /// Creates a new instance of the Kaufman Efficiency Ratio (ER).
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `period`: The lookback period for calculating ER.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - The `data` length is shorter than `period + 1`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::er::Er;
+    ///
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    /// let er = Er::new(&price_data, 3);
+    ///
+    /// assert!(er.is_ok());
+    /// ```
+    pub fn new(data: &'a [f64], period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0".to_string());
+        }
+        if data.len() < period + 1 {
+            return Err("Period cannot be greater than input data length".to_string());
+        }
+
+        Ok(Self {
+            data,
+            period,
+            index: 0,
+            sum_roc: 0.0,
+            last_price: 0.0,
+            window: CircularBuffer::new(period + 1),
+            seeded: false,
+        })
+    }
+
+    /// Creates a streaming-only instance of the ER with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time. Feed samples through
+    /// [`Streaming::update`]; [`Indicator::calculate`] will report an empty result since
+    /// there is no slice to replay.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    pub fn new_streaming(period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0".to_string());
+        }
+
+        Ok(Self {
+            data: &[],
+            period,
+            index: 0,
+            sum_roc: 0.0,
+            last_price: 0.0,
+            window: CircularBuffer::new(period + 1),
+            seeded: false,
+        })
+    }
+}
+
+impl Streaming for Er<'_> {
+    /// Advances the ER by exactly one sample.
+    ///
+    /// Backs the rolling denominator with a [`CircularBuffer`] holding the last
+    /// `period + 1` prices: on every push the oldest absolute change drops out of the
+    /// sum and the newest one is added in, keeping the update O(1) regardless of `period`.
+    /// Returns `None` until `period + 1` samples have been pushed.
+    fn update(&mut self, value: f64) -> Option<f64> {
+        if !self.seeded {
+            self.window.push(value);
+            if !self.window.is_full() {
+                return None;
+            }
+
+            // `window` now holds the first `period + 1` prices, contiguous since nothing
+            // has been evicted yet.
+            let prices = self.window.as_slice();
+
+            self.sum_roc = prices[1..self.period]
+                .iter()
+                .zip(prices[..self.period - 1].iter())
+                .map(|(curr, prev)| (curr - prev).abs())
+                .sum();
+
+            let price_change = (prices[self.period] - prices[0]).abs();
+            self.sum_roc += (prices[self.period] - prices[self.period - 1]).abs();
+            self.last_price = prices[self.period];
+
+            self.seeded = true;
+
+            let er = if self.sum_roc == 0.0 { 0.0 } else { price_change / self.sum_roc };
+            return Some(er);
+        }
+
+        let old_trailing = *self.window.front().unwrap();
+        self.window.push(value);
+        let new_trailing = *self.window.front().unwrap();
+
+        let price_change = (value - new_trailing).abs();
+        self.sum_roc -= (new_trailing - old_trailing).abs();
+        self.sum_roc += (value - self.last_price).abs();
+        self.last_price = value;
+
+        let er = if self.sum_roc == 0.0 { 0.0 } else { price_change / self.sum_roc };
+        Some(er)
+    }
+
+    /// Clears the rolling `sum_roc` window, as if freshly constructed.
+    fn reset(&mut self) {
+        self.sum_roc = 0.0;
+        self.last_price = 0.0;
+        self.window.clear();
+        self.seeded = false;
+    }
+}
+
+impl Iterator for Er<'_> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.data.len() {
+            let value = self.data[self.index];
+            self.index += 1;
+
+            if let Some(result) = self.update(value) {
+                return Some(result);
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a> Indicator<'a> for Er<'a> {
+    type Output = Vec<f64>;
+
+    /// Computes the Kaufman Efficiency Ratio (ER) for the given data.
+    ///
+    /// Returns a vector containing the ER values over the dataset.
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        Ok(self.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_er_valid() {
+        let price_data = vec![
+            5.29, 12.66, 9.86, 8.16, 2.49, 2.49, 1.24, 11.58, 8.19, 9.56, 0.76, 12.91,
+        ];
+        let period = 5;
+
+        let mut er = Er::new(&price_data, period).unwrap();
+        let result = er.calculate().unwrap();
+
+        let mut expected = Vec::new();
+        for t in period..price_data.len() {
+            let numerator = (price_data[t] - price_data[t - period]).abs();
+            let denominator: f64 = (t - period + 1..=t)
+                .map(|i| (price_data[i] - price_data[i - 1]).abs())
+                .sum();
+            expected.push(if denominator == 0.0 { 0.0 } else { numerator / denominator });
+        }
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_er_constant_values_is_zero() {
+        let price_data = vec![5.0; 10];
+        let period = 3;
+
+        let mut er = Er::new(&price_data, period).unwrap();
+        let result = er.calculate().unwrap();
+
+        for value in result {
+            assert!((value - 0.0).abs() < 1e-9, "ER should be 0 for a flat price series");
+        }
+    }
+
+    #[test]
+    fn test_er_invalid_input() {
+        let price_data = vec![];
+
+        assert!(
+            Er::new(&price_data, 3).is_err(),
+            "ER should return an error for empty input."
+        );
+    }
+
+    #[test]
+    fn test_er_short_data() {
+        let price_data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            Er::new(&price_data, 3).is_err(),
+            "ER should return an error when data is shorter than period + 1."
+        );
+    }
+
+    #[test]
+    fn test_er_streaming_matches_slice_based() {
+        let price_data = vec![
+            5.29, 12.66, 9.86, 8.16, 2.49, 2.49, 1.24, 11.58, 8.19, 9.56, 0.76, 12.91,
+        ];
+        let period = 5;
+
+        let expected = Er::new(&price_data, period).unwrap().calculate().unwrap();
+
+        let mut streaming = Er::new_streaming(period).unwrap();
+        let streamed: Vec<f64> = price_data.iter().filter_map(|&value| streaming.update(value)).collect();
+
+        assert_eq!(streamed.len(), expected.len());
+        for (r, e) in streamed.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+}
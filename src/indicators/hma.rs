@@ -0,0 +1,174 @@
+//! # Hull Moving Average (HMA) Indicator
+//!
+//! The **Hull Moving Average (HMA)**, developed by Alan Hull, significantly reduces the lag
+//! of a standard [`crate::indicators::wma::Wma`] while keeping the curve smooth, by
+//! combining WMAs of different lengths.
+//!
+//! ## Formula
+//! ```text
+//! HMA = WMA(2 * WMA(price, period / 2) - WMA(price, period), round(sqrt(period)))
+//! ```
+//! The difference series `2 * WMA(price, period / 2) - WMA(price, period)` overweights
+//! recent price changes, and the final WMA over `round(sqrt(period))` samples smooths that
+//! difference back out without reintroducing much lag.
+//!
+//! ## Performance Considerations
+//! - Materializes the two intermediate WMAs and their difference series before the final
+//!   WMA pass, the same "eager computation" approach used by
+//!   [`crate::indicators::t3::T3`] for multi-stage moving averages.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::hma::Hma;
+//!
+//! let price_data: Vec<f64> = (1..=40).map(|x| x as f64).collect();
+//! let period = 9;
+//!
+//! let mut hma = Hma::new(&price_data, period).unwrap();
+//! let hma_values = hma.calculate().unwrap();
+//!
+//! println!("HMA Values: {:?}", hma_values);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::indicators::wma::Wma;
+use crate::Indicator;
+
+/// **The Hull Moving Average (HMA) Indicator**
+///
+/// HMA combines a half-length and a full-length WMA into a difference series, then
+/// smooths that difference with a `round(sqrt(period))`-length WMA, producing a
+/// low-lag, low-noise moving average.
+#[derive(Clone, Debug)]
+pub struct Hma {
+    /// The precomputed HMA values.
+    values: Vec<f64>,
+    /// Current index in the iteration process.
+    index: usize,
+}
+
+impl Hma {
+    /// Creates a new instance of the Hull Moving Average (HMA).
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `period`: The lookback period. Internally split into a `period / 2`-length WMA, a
+    ///   `period`-length WMA, and a final `round(sqrt(period))`-length WMA.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is less than 2 (so `period / 2` is never zero).
+    /// - The `data` is too short to produce at least one HMA value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::indicators::hma::Hma;
+    ///
+    /// let price_data: Vec<f64> = (1..=40).map(|x| x as f64).collect();
+    /// let hma = Hma::new(&price_data, 9);
+    ///
+    /// assert!(hma.is_ok());
+    /// ```
+    pub fn new(data: &[f64], period: usize) -> Result<Self, String> {
+        if period < 2 {
+            return Err("Period must be at least 2.".to_string());
+        }
+        if data.len() < period {
+            return Err("Period cannot be greater than input data length.".to_string());
+        }
+
+        let half_period = period / 2;
+        let sqrt_period = (period as f64).sqrt().round() as usize;
+
+        let wma_half = Wma::new(data, half_period)?.calculate()?;
+        let wma_full = Wma::new(data, period)?.calculate()?;
+
+        // `wma_half` starts `period - half_period` samples earlier than `wma_full`; trim it
+        // down so both are aligned before combining.
+        let offset = wma_half.len() - wma_full.len();
+        let diff: Vec<f64> = wma_full
+            .iter()
+            .enumerate()
+            .map(|(i, full)| 2.0 * wma_half[i + offset] - full)
+            .collect();
+
+        let values = Wma::new(&diff, sqrt_period)?.calculate()?;
+
+        Ok(Self { values, index: 0 })
+    }
+}
+
+impl Iterator for Hma {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.values.get(self.index).copied()?;
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.values.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> Indicator<'a> for Hma {
+    type Output = Vec<f64>;
+
+    /// Computes the Hull Moving Average (HMA) for the given data.
+    ///
+    /// Returns a vector containing the HMA values over the dataset.
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        Ok(self.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hma_matches_manual_wma_chain() {
+        let data: Vec<f64> = (1..=40).map(|x| (x as f64 * 0.3).sin() * 5.0 + x as f64).collect();
+        let period = 9;
+
+        let mut hma = Hma::new(&data, period).unwrap();
+        let result = hma.calculate().unwrap();
+
+        let wma_half = Wma::new(&data, period / 2).unwrap().calculate().unwrap();
+        let wma_full = Wma::new(&data, period).unwrap().calculate().unwrap();
+        let offset = wma_half.len() - wma_full.len();
+        let diff: Vec<f64> = wma_full
+            .iter()
+            .enumerate()
+            .map(|(i, full)| 2.0 * wma_half[i + offset] - full)
+            .collect();
+        let sqrt_period = (period as f64).sqrt().round() as usize;
+        let expected = Wma::new(&diff, sqrt_period).unwrap().calculate().unwrap();
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_hma_invalid_period() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(Hma::new(&data, 1).is_err(), "HMA should return an error for a period below 2.");
+    }
+
+    #[test]
+    fn test_hma_short_data() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            Hma::new(&data, 10).is_err(),
+            "HMA should return an error when data is too short."
+        );
+    }
+}
@@ -56,11 +56,13 @@
 //!
 //! ## Struct Definition
 
-use crate::Indicator;
+use crate::circular_buffer::CircularBuffer;
+use crate::indicators::atr::Atr;
+use crate::{Indicator, Streaming};
 
 /// **Kaufman Adaptive Moving Average (KAMA) Indicator**
 ///
-/// The KAMA indicator dynamically adjusts its smoothing factor based on market conditions, 
+/// The KAMA indicator dynamically adjusts its smoothing factor based on market conditions,
 /// reducing lag in trends while filtering noise in sideways movements.
 #[derive(Clone, Debug)]
 pub struct Kama<'a> {
@@ -76,10 +78,14 @@ pub struct Kama<'a> {
     index: usize,
     /// The previously computed KAMA value.
     prev_kama: f64,
-    /// Rolling sum of absolute price changes.
+    /// Rolling sum of absolute price changes, maintained in O(1) via `window`.
     sum_roc: f64,
-    /// Last trailing value used for ROC calculations.
-    trailing_value: f64,
+    /// The last price pushed, used to extend `sum_roc` by one term per update.
+    last_price: f64,
+    /// Sliding window of the last `period + 1` prices backing the rolling `sum_roc`.
+    window: CircularBuffer<f64>,
+    /// Whether the warm-up window has produced the first KAMA value yet.
+    seeded: bool,
 }
 
 impl<'a> Kama<'a> {
@@ -124,40 +130,45 @@ impl<'a> Kama<'a> {
         let fast_sc = 2.0 / (fast as f64 + 1.0);
         let slow_sc = 2.0 / (slow as f64 + 1.0);
 
-        // Initialize sum of absolute price changes
-        let sum_roc = data[1..period].iter()
-            .zip(data[..period - 1].iter())
-            .map(|(curr, prev)| (curr - prev).abs())
-            .sum();
-
-        let prev_kama = data[period - 1]; // Initialize KAMA with last value in period
-
         Ok(Self {
             data,
             period,
             fast: fast_sc,
             slow: slow_sc,
-            index: period,
-            prev_kama,
-            sum_roc,
-            trailing_value: data[0],
+            index: 0,
+            prev_kama: 0.0,
+            sum_roc: 0.0,
+            last_price: 0.0,
+            window: CircularBuffer::new(period + 1),
+            seeded: false,
         })
     }
 
-    /// Computes the Efficiency Ratio (ER) with a rolling sum update.
-    fn calculate_er(&mut self, start_index: usize) -> f64 {
-        let price_change = (self.data[start_index] - self.data[start_index - self.period]).abs();
-
-        // Update rolling sum of absolute price changes
-        self.sum_roc -= (self.data[start_index - self.period] - self.trailing_value).abs();
-        self.sum_roc += (self.data[start_index] - self.data[start_index - 1]).abs();
-        self.trailing_value = self.data[start_index - self.period];
-
-        if self.sum_roc == 0.0 {
-            return 0.0;
+    /// Creates a streaming-only instance of the KAMA with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time. Feed samples through
+    /// [`Streaming::update`]; [`Indicator::calculate`] will report an empty result since
+    /// there is no slice to replay.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    pub fn new_streaming(period: usize, fast: usize, slow: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0.".to_string());
         }
 
-        price_change / self.sum_roc
+        Ok(Self {
+            data: &[],
+            period,
+            fast: 2.0 / (fast as f64 + 1.0),
+            slow: 2.0 / (slow as f64 + 1.0),
+            index: 0,
+            prev_kama: 0.0,
+            sum_roc: 0.0,
+            last_price: 0.0,
+            window: CircularBuffer::new(period + 1),
+            seeded: false,
+        })
     }
 
     /// Computes the smoothing constant (SC).
@@ -167,25 +178,85 @@ impl<'a> Kama<'a> {
     }
 }
 
-impl Iterator for Kama<'_> {
-    type Item = f64;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.data.len() {
-            return None;
+impl Streaming for Kama<'_> {
+    /// Advances the KAMA by exactly one sample.
+    ///
+    /// Backs the rolling `sum_roc` with a [`CircularBuffer`] holding the last
+    /// `period + 1` prices: on every push the oldest absolute change drops out of the
+    /// sum and the newest one is added in, keeping the update O(1) regardless of `period`.
+    /// Returns `None` until `period + 1` samples have been pushed.
+    fn update(&mut self, value: f64) -> Option<f64> {
+        if !self.seeded {
+            self.window.push(value);
+            if !self.window.is_full() {
+                return None;
+            }
+
+            // `window` now holds the first `period + 1` prices, contiguous since nothing
+            // has been evicted yet.
+            let prices = self.window.as_slice();
+
+            self.sum_roc = prices[1..self.period]
+                .iter()
+                .zip(prices[..self.period - 1].iter())
+                .map(|(curr, prev)| (curr - prev).abs())
+                .sum();
+            self.prev_kama = prices[self.period - 1];
+
+            let price_change = (prices[self.period] - prices[0]).abs();
+            self.sum_roc += (prices[self.period] - prices[self.period - 1]).abs();
+            self.last_price = prices[self.period];
+
+            let er = if self.sum_roc == 0.0 { 0.0 } else { price_change / self.sum_roc };
+            let sc = self.calculate_sc(er);
+
+            self.prev_kama += sc * (prices[self.period] - self.prev_kama);
+            self.seeded = true;
+
+            return Some(self.prev_kama);
         }
 
-        // Compute Efficiency Ratio (ER)
-        let er = self.calculate_er(self.index);
+        let old_trailing = *self.window.front().unwrap();
+        self.window.push(value);
+        let new_trailing = *self.window.front().unwrap();
+
+        let price_change = (value - new_trailing).abs();
+        self.sum_roc -= (new_trailing - old_trailing).abs();
+        self.sum_roc += (value - self.last_price).abs();
+        self.last_price = value;
+
+        let er = if self.sum_roc == 0.0 { 0.0 } else { price_change / self.sum_roc };
         let sc = self.calculate_sc(er);
 
-        // Compute KAMA using an EMA-like formula
-        let kama = self.prev_kama + sc * (self.data[self.index] - self.prev_kama);
+        self.prev_kama += sc * (value - self.prev_kama);
 
-        self.prev_kama = kama;
-        self.index += 1;
+        Some(self.prev_kama)
+    }
 
-        Some(kama)
+    /// Clears the rolling `sum_roc` window and `prev_kama`, as if freshly constructed.
+    fn reset(&mut self) {
+        self.prev_kama = 0.0;
+        self.sum_roc = 0.0;
+        self.last_price = 0.0;
+        self.window.clear();
+        self.seeded = false;
+    }
+}
+
+impl Iterator for Kama<'_> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.data.len() {
+            let value = self.data[self.index];
+            self.index += 1;
+
+            if let Some(result) = self.update(value) {
+                return Some(result);
+            }
+        }
+
+        None
     }
 }
 
@@ -215,6 +286,101 @@ impl<'a> Indicator<'a> for Kama<'a> {
     }
 }
 
+impl<'a> Kama<'a> {
+    /// Creates a KAMA instance paired with upper/lower volatility bands offset by a
+    /// multiple of Average True Range, matching the common "KAMA + ATR bands" presentation.
+    ///
+    /// # Arguments
+    /// - `high`, `low`, `close`: OHLC price data used to compute the ATR; `close` also
+    ///   feeds the KAMA line itself.
+    /// - `period`, `fast`, `slow`: KAMA parameters (see [`Kama::new`]).
+    /// - `atr_period`: The lookback period for the ATR smoothing (see [`Atr::new`]).
+    /// - `mult`: The ATR multiplier applied to the upper and lower bands.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying [`Kama`] or [`Atr`] construction fails.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::kama::Kama;
+    ///
+    /// let high = vec![2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    /// let low = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+    /// let close = vec![1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5];
+    ///
+    /// let mut bands = Kama::with_bands(&high, &low, &close, 2, 2, 30, 2, 2.0).unwrap();
+    /// let (kama, upper, lower) = bands.calculate().unwrap();
+    /// println!("KAMA: {:?}, Upper: {:?}, Lower: {:?}", kama, upper, lower);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_bands(
+        high: &'a [f64],
+        low: &'a [f64],
+        close: &'a [f64],
+        period: usize,
+        fast: usize,
+        slow: usize,
+        atr_period: usize,
+        mult: f64,
+    ) -> Result<KamaBands<'a>, String> {
+        let mut kama = Kama::new(close, period, fast, slow)?;
+        let mut atr = Atr::new(high, low, close, atr_period)?;
+
+        // The two iterators emit their first value at different absolute indices
+        // (`period` for KAMA, `atr_period` for ATR); skip the quicker one forward
+        // so both paths line up on the same bar before zipping them together.
+        for _ in 0..atr_period.saturating_sub(period) {
+            kama.next();
+        }
+        for _ in 0..period.saturating_sub(atr_period) {
+            atr.next();
+        }
+
+        Ok(KamaBands { kama, atr, mult })
+    }
+}
+
+/// **KAMA with ATR Volatility Bands**
+///
+/// Pairs the [`Kama`] line with an upper and lower band offset by `mult` times the
+/// Average True Range ([`Atr`]), in the style of "KAMA + ATR bands" overlays used by
+/// charting tools. Created via [`Kama::with_bands`].
+pub struct KamaBands<'a> {
+    kama: Kama<'a>,
+    atr: Atr<'a>,
+    mult: f64,
+}
+
+impl Iterator for KamaBands<'_> {
+    type Item = (f64, f64, f64); // (kama, upper_band, lower_band)
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let kama = self.kama.next()?;
+        let atr = self.atr.next()?;
+
+        Some((kama, kama + self.mult * atr, kama - self.mult * atr))
+    }
+}
+
+impl<'a> Indicator<'a> for KamaBands<'a> {
+    type Output = (Vec<f64>, Vec<f64>, Vec<f64>); // (kama, upper_band, lower_band)
+
+    /// Computes the KAMA line together with its upper and lower ATR bands.
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        let mut kama_values = Vec::new();
+        let mut upper_band = Vec::new();
+        let mut lower_band = Vec::new();
+
+        self.by_ref().for_each(|(kama, upper, lower)| {
+            kama_values.push(kama);
+            upper_band.push(upper);
+            lower_band.push(lower);
+        });
+
+        Ok((kama_values, upper_band, lower_band))
+    }
+}
 
 
 
@@ -306,4 +472,56 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_kama_streaming_matches_slice_based() {
+        let price_data = vec![
+            5.29, 12.66, 9.86, 8.16, 2.49, 2.49, 1.24, 11.58, 8.19, 9.56, 0.76, 12.91,
+            11.15, 3.21, 2.82, 2.84, 4.39, 7.21, 6.02, 4.22,
+        ];
+        let period = 5;
+        let fast = 2;
+        let slow = 30;
+
+        let expected = Kama::new(&price_data, period, fast, slow).unwrap().calculate().unwrap();
+
+        let mut streaming = Kama::new_streaming(period, fast, slow).unwrap();
+        let streamed: Vec<f64> = price_data.iter().filter_map(|&value| streaming.update(value)).collect();
+
+        assert_eq!(streamed.len(), expected.len());
+        for (r, e) in streamed.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-6, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_kama_with_bands() {
+        let high = vec![
+            6.10162623, 14.56635718, 11.35078849, 9.39745112, 2.88643542,
+            2.88638959, 1.45035886, 13.32573468, 9.43211852, 11.0053379,
+            0.89911054, 14.85282872, 12.83164102, 3.71344431, 3.26546744,
+        ];
+        let low = vec![
+            5.06640197, 10.75711748, 7.92529914, 6.52404504, 1.81123384,
+            2.20442298, -0.16004275, 10.64371026, 7.85812735, 8.51916387,
+            0.59466181, 11.08229124, 10.55831803, 1.85120766, 2.12774896,
+        ];
+        let close = vec![
+            5.29, 12.66, 9.86, 8.16, 2.49, 2.49, 1.24, 11.58,
+            8.19, 9.56, 0.76, 12.91, 11.15, 3.21, 2.82,
+        ];
+        let period = 5;
+
+        let mut bands = Kama::with_bands(&high, &low, &close, period, 2, 30, period, 2.0).unwrap();
+        let (kama_values, upper_band, lower_band) = bands.calculate().unwrap();
+
+        assert_eq!(kama_values.len(), upper_band.len());
+        assert_eq!(kama_values.len(), lower_band.len());
+        assert!(!kama_values.is_empty());
+
+        for ((&k, &u), &l) in kama_values.iter().zip(upper_band.iter()).zip(lower_band.iter()) {
+            assert!(u >= k, "Upper band should never be below the KAMA line");
+            assert!(l <= k, "Lower band should never be above the KAMA line");
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,261 @@
+//! # Linear Regression (LinReg)
+//!
+//! Fits a least-squares line `y = a + b·x` over a rolling window of `period` samples, with
+//! `x` taken as the fixed grid `0..period-1` (the bar position within the window). Also
+//! known as the Time Series Forecast (TSF) when the fitted line is projected forward to the
+//! most recent bar.
+//!
+//! ## Formula
+//! Since the `x` grid never changes between windows, `Σx` and `Σx²` are constants:
+//! ```text
+//! Σx  = p(p-1)/2
+//! Σx² = (p-1)p(2p-1)/6
+//! ```
+//! Only `Σy` and `Σ(x·y)` depend on the window's contents, and both can be updated in O(1)
+//! as the window slides by one bar (`y_out` leaves, `y_in` enters):
+//! ```text
+//! Σxy' = Σxy - Σy + y_out + (p-1)·y_in
+//! Σy'  = Σy - y_out + y_in
+//! ```
+//! The slope, intercept, and the line's value at the most recent bar in the window are then:
+//! ```text
+//! slope b     = (p·Σxy - Σx·Σy) / (p·Σx² - (Σx)²)
+//! intercept a = (Σy - b·Σx) / p
+//! value       = a + b·(p-1)
+//! ```
+//!
+//! ## Advantages of LinReg in Technical Analysis
+//! - **Smooths price action** while remaining responsive, since it fits to the actual trend
+//!   rather than just averaging.
+//! - **Slope** quantifies trend direction and strength directly.
+//! - **Forecasting** → the line's projected value is usable as a one-step-ahead estimate (TSF).
+//!
+//! ## Performance Considerations
+//! - `Σx` and `Σx²` are computed once at construction; `Σy` and `Σ(x·y)` are maintained
+//!   incrementally, so each step after the first window is O(1) regardless of `period`.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::linreg::LinReg;
+//!
+//! let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+//! let period = 3;
+//!
+//! let mut linreg = LinReg::new(&price_data, period).unwrap();
+//! let (values, slopes, intercepts) = linreg.calculate().unwrap();
+//!
+//! println!("LinReg Values: {:?}", values);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::Indicator;
+
+/// **The Linear Regression (LinReg) Indicator**
+///
+/// Fits a least-squares line over a rolling window and reports the line's value at the most
+/// recent bar (the TSF value), its slope, and its intercept.
+#[derive(Clone, Debug)]
+pub struct LinReg<'a> {
+    /// Reference to the input price data.
+    data: &'a [f64],
+    /// The lookback period for the regression window.
+    period: usize,
+    /// Current index in the iteration process.
+    index: usize,
+    /// Sum of the fixed x-grid `0..period-1`, constant for a given `period`.
+    sum_x: f64,
+    /// Sum of squares of the fixed x-grid `0..period-1`, constant for a given `period`.
+    sum_xx: f64,
+    /// Rolling sum of `y` over the current window.
+    sum_y: f64,
+    /// Rolling sum of `x·y` over the current window.
+    sum_xy: f64,
+}
+
+impl<'a> LinReg<'a> {
+    /// Creates a new instance of the Linear Regression (LinReg) indicator.
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `period`: The lookback period for the regression window.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is less than `2` (a line cannot be fit to a single point).
+    /// - The `data` length is shorter than the `period`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::linreg::LinReg;
+    ///
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let linreg = LinReg::new(&price_data, 3);
+    ///
+    /// assert!(linreg.is_ok());
+    /// ```
+    pub fn new(data: &'a [f64], period: usize) -> Result<Self, String> {
+        if period < 2 {
+            return Err("Period must be at least 2".to_string());
+        }
+        if data.len() < period {
+            return Err("Insufficient data for the given period".to_string());
+        }
+
+        let p = period as f64;
+        let sum_x = p * (p - 1.0) / 2.0;
+        let sum_xx = (p - 1.0) * p * (2.0 * p - 1.0) / 6.0;
+
+        Ok(Self {
+            data,
+            period,
+            index: 0,
+            sum_x,
+            sum_xx,
+            sum_y: 0.0,
+            sum_xy: 0.0,
+        })
+    }
+
+    /// Fits the window's current `sum_y`/`sum_xy` into `(value, slope, intercept)`.
+    fn fit(&self) -> (f64, f64, f64) {
+        let p = self.period as f64;
+        let slope = (p * self.sum_xy - self.sum_x * self.sum_y) / (p * self.sum_xx - self.sum_x * self.sum_x);
+        let intercept = (self.sum_y - slope * self.sum_x) / p;
+        let value = intercept + slope * (p - 1.0);
+
+        (value, slope, intercept)
+    }
+}
+
+impl Iterator for LinReg<'_> {
+    type Item = (f64, f64, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index + self.period > self.data.len() {
+            return None;
+        }
+
+        if self.index == 0 {
+            let window = &self.data[..self.period];
+            self.sum_y = window.iter().sum();
+            self.sum_xy = window.iter().enumerate().map(|(x, &y)| x as f64 * y).sum();
+        } else {
+            let outgoing = self.data[self.index - 1];
+            let incoming = self.data[self.index + self.period - 1];
+
+            self.sum_xy = self.sum_xy - self.sum_y + outgoing + (self.period - 1) as f64 * incoming;
+            self.sum_y = self.sum_y - outgoing + incoming;
+        }
+
+        let result = self.fit();
+        self.index += 1;
+        Some(result)
+    }
+}
+
+impl<'a> Indicator<'a> for LinReg<'a> {
+    type Output = (Vec<f64>, Vec<f64>, Vec<f64>);
+
+    /// Computes the Linear Regression for the given data.
+    ///
+    /// Returns `(values, slopes, intercepts)`, where `values` is the fitted line's value at
+    /// the most recent bar of each window (the TSF value).
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        let mut values = Vec::new();
+        let mut slopes = Vec::new();
+        let mut intercepts = Vec::new();
+
+        for (value, slope, intercept) in self {
+            values.push(value);
+            slopes.push(slope);
+            intercepts.push(intercept);
+        }
+
+        Ok((values, slopes, intercepts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manual_fit(window: &[f64]) -> (f64, f64, f64) {
+        let p = window.len() as f64;
+        let sum_x: f64 = (0..window.len()).map(|x| x as f64).sum();
+        let sum_xx: f64 = (0..window.len()).map(|x| (x as f64) * (x as f64)).sum();
+        let sum_y: f64 = window.iter().sum();
+        let sum_xy: f64 = window.iter().enumerate().map(|(x, &y)| x as f64 * y).sum();
+
+        let slope = (p * sum_xy - sum_x * sum_y) / (p * sum_xx - sum_x * sum_x);
+        let intercept = (sum_y - slope * sum_x) / p;
+        let value = intercept + slope * (p - 1.0);
+
+        (value, slope, intercept)
+    }
+
+    #[test]
+    fn test_linreg_matches_manual_least_squares() {
+        let data = vec![5.29, 12.66, 9.86, 8.16, 2.49, 2.49, 1.24, 11.58, 8.19, 9.56];
+        let period = 4;
+
+        let (values, slopes, intercepts) = LinReg::new(&data, period).unwrap().calculate().unwrap();
+
+        let expected: Vec<(f64, f64, f64)> = data.windows(period).map(manual_fit).collect();
+
+        assert_eq!(values.len(), expected.len());
+        for (i, (e_value, e_slope, e_intercept)) in expected.iter().enumerate() {
+            assert!((values[i] - e_value).abs() < 1e-9);
+            assert!((slopes[i] - e_slope).abs() < 1e-9);
+            assert!((intercepts[i] - e_intercept).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_linreg_perfect_uptrend_has_slope_one() {
+        let data: Vec<f64> = (1..=10).map(|x| x as f64).collect();
+        let period = 5;
+
+        let (values, slopes, intercepts) = LinReg::new(&data, period).unwrap().calculate().unwrap();
+
+        for slope in &slopes {
+            assert!((slope - 1.0).abs() < 1e-9, "Expected slope 1.0, got {}", slope);
+        }
+        for (value, &intercept) in values.iter().zip(intercepts.iter()) {
+            // With slope 1, the projected value sits `period - 1` above the intercept.
+            assert!((value - (intercept + (period - 1) as f64)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_linreg_flat_series_has_zero_slope() {
+        let data = vec![5.0; 10];
+        let period = 4;
+
+        let (values, slopes, _) = LinReg::new(&data, period).unwrap().calculate().unwrap();
+
+        for slope in &slopes {
+            assert!((slope - 0.0).abs() < 1e-9);
+        }
+        for value in &values {
+            assert!((value - 5.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_linreg_invalid_period() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(LinReg::new(&data, 0).is_err());
+        assert!(LinReg::new(&data, 1).is_err());
+    }
+
+    #[test]
+    fn test_linreg_short_data() {
+        let data = vec![1.0, 2.0];
+
+        assert!(LinReg::new(&data, 3).is_err());
+    }
+}
@@ -0,0 +1,233 @@
+//! # Moving Average Convergence Divergence (MACD) Indicator
+//!
+//! **MACD** measures the relationship between two [`crate::indicators::ema::Ema`] values of
+//! different lengths. The difference between a fast and a slow EMA (the **MACD line**) tracks
+//! momentum shifts; a third EMA of the MACD line (the **signal line**) smooths it further, and
+//! the gap between the two (the **histogram**) highlights when momentum is accelerating or
+//! fading.
+//!
+//! ## Calculation
+//! 1. `fast_ema = Ema(close, fast_period)`, `slow_ema = Ema(close, slow_period)`.
+//! 2. Since the slow EMA starts emitting `slow_period - fast_period` samples later than the
+//!    fast EMA, the fast EMA is trimmed to the same length before subtracting:
+//!    `macd = fast_ema[slow_period - fast_period..] - slow_ema`.
+//! 3. `signal = Ema(macd, signal_period)`, which itself starts `signal_period - 1` samples
+//!    later than `macd`, so `macd` is trimmed again to line up with `signal`.
+//! 4. `histogram = macd - signal`.
+//!
+//! All three output vectors share the same length and the same alignment, so callers never
+//! have to re-derive the warmup offsets themselves.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::macd::Macd;
+//!
+//! let price_data: Vec<f64> = (1..=40).map(|x| (x as f64 * 0.3).sin() * 5.0 + x as f64).collect();
+//!
+//! let mut macd = Macd::new(&price_data, 12, 26, 9).unwrap();
+//! let (macd_line, signal_line, histogram) = macd.calculate().unwrap();
+//!
+//! println!("MACD: {:?}", macd_line);
+//! println!("Signal: {:?}", signal_line);
+//! println!("Histogram: {:?}", histogram);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::indicators::ema::Ema;
+use crate::Indicator;
+
+/// **The Moving Average Convergence Divergence (MACD) Indicator**
+///
+/// MACD composes two [`crate::indicators::ema::Ema`] instances into a momentum line, then
+/// smooths that line with a third EMA to produce a signal line and histogram.
+#[derive(Clone, Debug)]
+pub struct Macd {
+    /// The precomputed MACD line, trimmed to align with `signal`.
+    macd: Vec<f64>,
+    /// The precomputed signal line (EMA of the MACD line).
+    signal: Vec<f64>,
+    /// The precomputed histogram (`macd - signal`).
+    histogram: Vec<f64>,
+    /// Current index in the iteration process.
+    index: usize,
+}
+
+impl Macd {
+    /// Creates a new instance of the Moving Average Convergence Divergence (MACD).
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `fast_period`: The lookback period for the fast EMA.
+    /// - `slow_period`: The lookback period for the slow EMA. Must be greater than `fast_period`.
+    /// - `signal_period`: The lookback period for the signal line EMA of the MACD line.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `fast_period`, `slow_period`, or `signal_period` is zero.
+    /// - `slow_period` is not greater than `fast_period`.
+    /// - The `data` is too short to produce at least one signal-line value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::indicators::macd::Macd;
+    ///
+    /// let price_data: Vec<f64> = (1..=40).map(|x| x as f64).collect();
+    /// let macd = Macd::new(&price_data, 12, 26, 9);
+    ///
+    /// assert!(macd.is_ok());
+    /// ```
+    pub fn new(data: &[f64], fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self, String> {
+        if fast_period == 0 || slow_period == 0 || signal_period == 0 {
+            return Err("Period must be greater than 0".to_string());
+        }
+        if slow_period <= fast_period {
+            return Err("Slow period must be greater than fast period".to_string());
+        }
+
+        let fast_ema = Ema::new(data, fast_period)?.calculate()?;
+        let slow_ema = Ema::new(data, slow_period)?.calculate()?;
+
+        // `fast_ema` starts `slow_period - fast_period` samples earlier than `slow_ema`; trim
+        // it down so both lines are aligned before subtracting.
+        let offset = slow_period - fast_period;
+        let macd_line: Vec<f64> = fast_ema[offset..]
+            .iter()
+            .zip(slow_ema.iter())
+            .map(|(fast, slow)| fast - slow)
+            .collect();
+
+        let signal = Ema::new(&macd_line, signal_period)?.calculate()?;
+
+        // `signal` starts `signal_period - 1` samples later than `macd_line`; trim `macd_line`
+        // down to match so all three outputs share the same length and alignment.
+        let macd: Vec<f64> = macd_line[macd_line.len() - signal.len()..].to_vec();
+        let histogram: Vec<f64> = macd.iter().zip(signal.iter()).map(|(m, s)| m - s).collect();
+
+        Ok(Self {
+            macd,
+            signal,
+            histogram,
+            index: 0,
+        })
+    }
+}
+
+impl Iterator for Macd {
+    type Item = (f64, f64, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = (
+            *self.macd.get(self.index)?,
+            *self.signal.get(self.index)?,
+            *self.histogram.get(self.index)?,
+        );
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.macd.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> Indicator<'a> for Macd {
+    type Output = (Vec<f64>, Vec<f64>, Vec<f64>);
+
+    /// Computes the MACD line, signal line, and histogram for the given data.
+    ///
+    /// Returns three aligned vectors: `(macd, signal, histogram)`.
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        let mut macd = Vec::with_capacity(self.macd.len());
+        let mut signal = Vec::with_capacity(self.signal.len());
+        let mut histogram = Vec::with_capacity(self.histogram.len());
+
+        for (m, s, h) in self {
+            macd.push(m);
+            signal.push(s);
+            histogram.push(h);
+        }
+
+        Ok((macd, signal, histogram))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_macd_matches_manual_ema_chain() {
+        let data: Vec<f64> = (1..=60).map(|x| (x as f64 * 0.2).sin() * 5.0 + x as f64).collect();
+        let (fast_period, slow_period, signal_period) = (5, 10, 4);
+
+        let mut macd = Macd::new(&data, fast_period, slow_period, signal_period).unwrap();
+        let (macd_line, signal_line, histogram) = macd.calculate().unwrap();
+
+        let fast_ema = Ema::new(&data, fast_period).unwrap().calculate().unwrap();
+        let slow_ema = Ema::new(&data, slow_period).unwrap().calculate().unwrap();
+        let offset = slow_period - fast_period;
+        let expected_macd_full: Vec<f64> = fast_ema[offset..]
+            .iter()
+            .zip(slow_ema.iter())
+            .map(|(f, s)| f - s)
+            .collect();
+        let expected_signal = Ema::new(&expected_macd_full, signal_period).unwrap().calculate().unwrap();
+        let expected_macd = expected_macd_full[expected_macd_full.len() - expected_signal.len()..].to_vec();
+        let expected_histogram: Vec<f64> = expected_macd
+            .iter()
+            .zip(expected_signal.iter())
+            .map(|(m, s)| m - s)
+            .collect();
+
+        assert_eq!(macd_line.len(), expected_macd.len());
+        assert_eq!(signal_line.len(), expected_signal.len());
+        assert_eq!(histogram.len(), expected_histogram.len());
+
+        for ((r, e), ((rs, es), (rh, eh))) in macd_line.iter().zip(expected_macd.iter()).zip(
+            signal_line
+                .iter()
+                .zip(expected_signal.iter())
+                .zip(histogram.iter().zip(expected_histogram.iter())),
+        ) {
+            assert!((r - e).abs() < 1e-9, "Expected MACD {}, got {}", e, r);
+            assert!((rs - es).abs() < 1e-9, "Expected signal {}, got {}", es, rs);
+            assert!((rh - eh).abs() < 1e-9, "Expected histogram {}, got {}", eh, rh);
+        }
+    }
+
+    #[test]
+    fn test_macd_histogram_is_macd_minus_signal() {
+        let data: Vec<f64> = (1..=50).map(|x| x as f64).collect();
+
+        let mut macd = Macd::new(&data, 5, 10, 3).unwrap();
+        let (macd_line, signal_line, histogram) = macd.calculate().unwrap();
+
+        for ((m, s), h) in macd_line.iter().zip(signal_line.iter()).zip(histogram.iter()) {
+            assert!((h - (m - s)).abs() < 1e-9, "Histogram should equal macd - signal");
+        }
+    }
+
+    #[test]
+    fn test_macd_invalid_periods() {
+        let data: Vec<f64> = (1..=50).map(|x| x as f64).collect();
+
+        assert!(Macd::new(&data, 0, 26, 9).is_err(), "MACD should reject a zero fast period.");
+        assert!(
+            Macd::new(&data, 26, 12, 9).is_err(),
+            "MACD should reject a slow period not greater than the fast period."
+        );
+    }
+
+    #[test]
+    fn test_macd_short_data() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            Macd::new(&data, 12, 26, 9).is_err(),
+            "MACD should return an error when data is too short."
+        );
+    }
+}
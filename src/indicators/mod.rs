@@ -0,0 +1,34 @@
+//! # Indicators
+//!
+//! This module contains the individual technical indicator implementations exposed by `tarq`.
+//! Each indicator lives in its own submodule and implements the [`crate::Indicator`] trait.
+
+pub mod alma;
+pub mod atr;
+pub mod bbands;
+pub mod bbands_signal;
+pub mod bbandwidth;
+pub mod bbpb;
+pub mod brownian_bands;
+pub mod cross;
+pub mod dema;
+pub mod ema;
+pub mod er;
+pub mod hma;
+pub mod kama;
+pub mod linreg;
+pub mod macd;
+pub mod momentum;
+pub mod price;
+pub mod ribbon;
+pub mod rsi;
+pub mod sma;
+pub mod smma;
+pub mod snatr;
+pub mod stddev;
+pub mod t3;
+pub mod tema;
+pub mod trima;
+pub mod volume;
+pub mod vwma;
+pub mod wma;
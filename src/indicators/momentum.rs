@@ -0,0 +1,150 @@
+//! # Momentum Functions
+//!
+//! Free-function wrappers around the crate's momentum indicators
+//! ([`crate::indicators::macd::Macd`], [`crate::indicators::rsi::Rsi`]) plus the Awesome
+//! Oscillator, which composes [`crate::indicators::sma::Sma`] over the median price. These
+//! exist so callers (including the Python bindings) can reach for momentum output without
+//! constructing and driving an iterator themselves.
+
+use crate::indicators::macd::Macd;
+use crate::indicators::price::median_price;
+use crate::indicators::rsi::Rsi;
+use crate::indicators::sma::Sma;
+use crate::Indicator;
+
+/// The `(macd, signal, histogram)` triple returned by [`macd`].
+pub type MacdOutput = (Vec<f64>, Vec<f64>, Vec<f64>);
+
+/// Computes the Moving Average Convergence Divergence (MACD) line, signal line, and
+/// histogram. See [`Macd`] for the full calculation.
+///
+/// # Errors
+/// Returns an error under the same conditions as [`Macd::new`].
+///
+/// # Example
+/// ```rust
+/// use tarq::indicators::momentum::macd;
+///
+/// let price_data: Vec<f64> = (1..=40).map(|x| x as f64).collect();
+/// let (macd_line, signal_line, histogram) = macd(&price_data, 12, 26, 9).unwrap();
+///
+/// assert_eq!(macd_line.len(), signal_line.len());
+/// assert_eq!(macd_line.len(), histogram.len());
+/// ```
+pub fn macd(data: &[f64], fast_period: usize, slow_period: usize, signal_period: usize) -> Result<MacdOutput, String> {
+    Macd::new(data, fast_period, slow_period, signal_period)?.calculate()
+}
+
+/// Computes the Relative Strength Index (RSI). See [`Rsi`] for the full calculation.
+///
+/// # Errors
+/// Returns an error under the same conditions as [`Rsi::new`].
+///
+/// # Example
+/// ```rust
+/// use tarq::indicators::momentum::rsi;
+///
+/// let price_data = vec![1.0, 2.0, 1.5, 2.5, 3.0, 2.8];
+/// let values = rsi(&price_data, 3).unwrap();
+///
+/// assert_eq!(values.len(), 3);
+/// ```
+pub fn rsi(data: &[f64], period: usize) -> Result<Vec<f64>, String> {
+    Rsi::new(data, period)?.calculate()
+}
+
+/// Computes the Awesome Oscillator: the difference between a short and a long
+/// [`Sma`] of the median price (`(H + L) / 2`).
+///
+/// The short SMA starts `long_period - short_period` samples earlier than the long SMA, so it
+/// is trimmed to the same length before subtracting, matching the alignment approach used by
+/// [`macd`].
+///
+/// # Errors
+/// Returns an error if:
+/// - `short_period` or `long_period` is zero.
+/// - `long_period` is not greater than `short_period`.
+/// - `high` and `low` differ in length, or are too short to produce at least one value.
+///
+/// # Example
+/// ```rust
+/// use tarq::indicators::momentum::aosc;
+///
+/// let high: Vec<f64> = (1..=40).map(|x| x as f64 + 1.0).collect();
+/// let low: Vec<f64> = (1..=40).map(|x| x as f64 - 1.0).collect();
+///
+/// let values = aosc(&high, &low, 5, 34).unwrap();
+/// assert_eq!(values.len(), 40 - 34 + 1);
+/// ```
+pub fn aosc(high: &[f64], low: &[f64], short_period: usize, long_period: usize) -> Result<Vec<f64>, String> {
+    if short_period == 0 || long_period == 0 {
+        return Err("Period must be greater than 0".to_string());
+    }
+    if long_period <= short_period {
+        return Err("Long period must be greater than short period".to_string());
+    }
+
+    let median = median_price(high, low)?;
+
+    let short_sma = Sma::new(&median, short_period)?.calculate()?;
+    let long_sma = Sma::new(&median, long_period)?.calculate()?;
+
+    let offset = long_period - short_period;
+    Ok(short_sma[offset..]
+        .iter()
+        .zip(long_sma.iter())
+        .map(|(short, long)| short - long)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_macd_matches_struct() {
+        let data: Vec<f64> = (1..=40).map(|x| (x as f64 * 0.2).sin() * 5.0 + x as f64).collect();
+
+        let (macd_line, signal_line, histogram) = macd(&data, 5, 10, 4).unwrap();
+        let expected = Macd::new(&data, 5, 10, 4).unwrap().calculate().unwrap();
+
+        assert_eq!((macd_line, signal_line, histogram), expected);
+    }
+
+    #[test]
+    fn test_rsi_matches_struct() {
+        let data = vec![1.0, 2.0, 1.5, 2.5, 3.0, 2.8, 3.2];
+
+        let result = rsi(&data, 3).unwrap();
+        let expected = Rsi::new(&data, 3).unwrap().calculate().unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_aosc_matches_manual_sma_difference() {
+        let high: Vec<f64> = (1..=40).map(|x| x as f64 + 1.0).collect();
+        let low: Vec<f64> = (1..=40).map(|x| x as f64 - 1.0).collect();
+
+        let result = aosc(&high, &low, 5, 10).unwrap();
+
+        let median = median_price(&high, &low).unwrap();
+        let short_sma = Sma::new(&median, 5).unwrap().calculate().unwrap();
+        let long_sma = Sma::new(&median, 10).unwrap().calculate().unwrap();
+        let expected: Vec<f64> = short_sma[5..].iter().zip(long_sma.iter()).map(|(s, l)| s - l).collect();
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_aosc_invalid_periods() {
+        let high = vec![1.0, 2.0, 3.0];
+        let low = vec![0.5, 1.5, 2.5];
+
+        assert!(aosc(&high, &low, 0, 10).is_err());
+        assert!(aosc(&high, &low, 10, 5).is_err());
+    }
+}
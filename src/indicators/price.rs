@@ -0,0 +1,146 @@
+//! # Price Transforms
+//!
+//! Several indicators (and the `momentum`/`volume` families in particular) operate on a
+//! single derived price rather than the raw close, but don't need a full [`crate::Indicator`]
+//! instance to get there — just a plain element-wise transform of OHLC data. This module holds
+//! those transforms as free functions so callers (including the Python bindings) can compute a
+//! derived price without pulling in an iterator.
+//!
+//! Unlike the lookback-based indicators elsewhere in [`crate::indicators`], these transforms
+//! have no warm-up period: every output vector is the same length as the input slices, one
+//! value per bar.
+
+fn validate_equal_len(lens: &[usize]) -> Result<(), String> {
+    if lens.iter().any(|&len| len != lens[0]) {
+        return Err("Input slices must all be the same length.".to_string());
+    }
+    if lens[0] == 0 {
+        return Err("Input data must not be empty.".to_string());
+    }
+
+    Ok(())
+}
+
+/// Computes the median price: `(H + L) / 2`.
+///
+/// # Errors
+/// Returns an error if `high` and `low` are empty or differ in length.
+///
+/// # Example
+/// ```rust
+/// use tarq::indicators::price::median_price;
+///
+/// let high = vec![12.0, 13.0];
+/// let low = vec![8.0, 9.0];
+///
+/// assert_eq!(median_price(&high, &low).unwrap(), vec![10.0, 11.0]);
+/// ```
+pub fn median_price(high: &[f64], low: &[f64]) -> Result<Vec<f64>, String> {
+    validate_equal_len(&[high.len(), low.len()])?;
+
+    Ok(high.iter().zip(low.iter()).map(|(&h, &l)| (h + l) / 2.0).collect())
+}
+
+/// Computes the typical price: `(H + L + C) / 3`.
+///
+/// # Errors
+/// Returns an error if `high`, `low`, and `close` are empty or differ in length.
+///
+/// # Example
+/// ```rust
+/// use tarq::indicators::price::typical_price;
+///
+/// let high = vec![12.0];
+/// let low = vec![8.0];
+/// let close = vec![11.0];
+///
+/// assert!((typical_price(&high, &low, &close).unwrap()[0] - 31.0 / 3.0).abs() < 1e-9);
+/// ```
+pub fn typical_price(high: &[f64], low: &[f64], close: &[f64]) -> Result<Vec<f64>, String> {
+    validate_equal_len(&[high.len(), low.len(), close.len()])?;
+
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((&h, &l), &c)| (h + l + c) / 3.0)
+        .collect())
+}
+
+/// Computes the weighted close: `(H + L + 2C) / 4`.
+///
+/// # Errors
+/// Returns an error if `high`, `low`, and `close` are empty or differ in length.
+///
+/// # Example
+/// ```rust
+/// use tarq::indicators::price::weighted_close;
+///
+/// let high = vec![12.0];
+/// let low = vec![8.0];
+/// let close = vec![11.0];
+///
+/// assert_eq!(weighted_close(&high, &low, &close).unwrap(), vec![10.5]);
+/// ```
+pub fn weighted_close(high: &[f64], low: &[f64], close: &[f64]) -> Result<Vec<f64>, String> {
+    validate_equal_len(&[high.len(), low.len(), close.len()])?;
+
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((&h, &l), &c)| (h + l + 2.0 * c) / 4.0)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median_price() {
+        let high = vec![12.0, 14.0];
+        let low = vec![8.0, 10.0];
+
+        assert_eq!(median_price(&high, &low).unwrap(), vec![10.0, 12.0]);
+    }
+
+    #[test]
+    fn test_typical_price() {
+        let high = vec![12.0];
+        let low = vec![8.0];
+        let close = vec![11.0];
+
+        let result = typical_price(&high, &low, &close).unwrap();
+        assert!((result[0] - 31.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_weighted_close() {
+        let high = vec![12.0];
+        let low = vec![8.0];
+        let close = vec![11.0];
+
+        assert_eq!(weighted_close(&high, &low, &close).unwrap(), vec![10.5]);
+    }
+
+    #[test]
+    fn test_price_transforms_reject_mismatched_lengths() {
+        let high = vec![12.0, 13.0];
+        let low = vec![8.0];
+        let close = vec![11.0, 11.5];
+
+        assert!(median_price(&high, &low).is_err());
+        assert!(typical_price(&high, &low, &close).is_err());
+        assert!(weighted_close(&high, &low, &close).is_err());
+    }
+
+    #[test]
+    fn test_price_transforms_reject_empty_input() {
+        let empty: Vec<f64> = vec![];
+
+        assert!(median_price(&empty, &empty).is_err());
+        assert!(typical_price(&empty, &empty, &empty).is_err());
+        assert!(weighted_close(&empty, &empty, &empty).is_err());
+    }
+}
@@ -0,0 +1,262 @@
+//! # Moving-Average Ribbon Indicator
+//!
+//! A **Ribbon** plots several moving averages of the same kind, spaced across a range of
+//! periods, over the same input data. Traders read trend strength from how tightly the
+//! resulting lines bunch together (consolidation) or fan apart (a strengthening trend).
+//!
+//! ## Calculation
+//! For a `count`-line ribbon starting at `start_period` with a `step`, line `i` (0-indexed)
+//! uses:
+//! ```text
+//! period_i = start_period + i * step
+//! line_i   = ma_type applied with period_i to the raw input data
+//! ```
+//! Each line can additionally be smoothed by re-applying `ma_type` with `period_i` to its
+//! own output `smoothing_passes` times in total (`smoothing_passes = 1` means no
+//! re-application beyond the initial pass), the same "feed a pass's output into the next
+//! pass" idea used by [`crate::indicators::t3::T3`] and [`crate::indicators::hma::Hma`] for
+//! their own chained stages.
+//!
+//! ## Performance Considerations
+//! - Every line is computed independently and eagerly, since each one only depends on the
+//!   raw input data (and its own prior smoothing passes), not on any other line.
+//! - Lines warm up at different rates (later lines use longer periods, and
+//!   `smoothing_passes > 1` extends the warm-up further), so [`Ribbon::calculate`]
+//!   right-aligns and trims every line down to the shortest line's length before returning,
+//!   letting consumers compare lines index-for-index.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::indicators::ribbon::Ribbon;
+//! use tarq::enums::MaKind;
+//!
+//! let price_data: Vec<f64> = (1..=30).map(|x| x as f64).collect();
+//!
+//! let ribbon = Ribbon::new(&price_data, MaKind::Ema, 3, 2, 4, 1).unwrap();
+//! let lines = ribbon.calculate();
+//!
+//! println!("Ribbon lines: {:?}", lines);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::enums::{MaKind, MovingAverage};
+use crate::Indicator;
+
+/// **The Moving-Average Ribbon Indicator**
+///
+/// `Ribbon` computes `count` lines of the same [`MaKind`], one per period in the
+/// arithmetic sequence `start_period, start_period + step, …`, each applied directly to
+/// the raw input data and optionally smoothed by re-applying the same moving average to
+/// its own output `smoothing_passes` times.
+///
+/// Unlike most indicators in this crate, a ribbon's lines have differing lengths and are
+/// computed eagerly at construction time rather than lazily via [`Iterator`], so `Ribbon`
+/// does not implement [`crate::Indicator`]; instead it exposes an inherent
+/// [`Ribbon::calculate`] method returning the already-aligned lines.
+#[derive(Clone, Debug)]
+pub struct Ribbon {
+    /// One line per period, in ascending period order, right-aligned and trimmed to a
+    /// common length.
+    lines: Vec<Vec<f64>>,
+}
+
+impl Ribbon {
+    /// Creates a new Moving-Average Ribbon.
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `ma_type`: Which moving average kind every line uses.
+    /// - `start_period`: The period used by the first (fastest) line.
+    /// - `step`: How much the period grows from one line to the next
+    ///   (`period_i = start_period + i * step`). Use `0` to give every line the same period.
+    /// - `count`: How many lines to produce (must be at least 1).
+    /// - `smoothing_passes`: How many times to apply `ma_type` to each line in total
+    ///   (must be between 1 and 5). `1` means each line is a single, unsmoothed pass;
+    ///   higher values feed a line's output back in as the input of the next pass.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `count` is zero.
+    /// - `smoothing_passes` is zero or greater than 5.
+    /// - `start_period` is zero.
+    /// - Any line's period is zero, or the data (after prior smoothing passes) is shorter
+    ///   than that line's period.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::indicators::ribbon::Ribbon;
+    /// use tarq::enums::MaKind;
+    ///
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    /// let ribbon = Ribbon::new(&price_data, MaKind::Sma, 2, 1, 3, 1);
+    ///
+    /// assert!(ribbon.is_ok());
+    /// ```
+    pub fn new(
+        data: &[f64],
+        ma_type: MaKind,
+        start_period: usize,
+        step: usize,
+        count: usize,
+        smoothing_passes: usize,
+    ) -> Result<Self, String> {
+        if count == 0 {
+            return Err("Count must be greater than 0".to_string());
+        }
+        if smoothing_passes == 0 || smoothing_passes > 5 {
+            return Err("Smoothing passes must be between 1 and 5".to_string());
+        }
+        if start_period == 0 {
+            return Err("Start period must be greater than 0".to_string());
+        }
+
+        let mut lines = Vec::with_capacity(count);
+
+        for i in 0..count {
+            let period = start_period + i * step;
+
+            let mut line = data.to_vec();
+            for _ in 0..smoothing_passes {
+                line = Self::apply_layer(&line, ma_type, period)?;
+            }
+
+            lines.push(line);
+        }
+
+        let min_len = lines.iter().map(Vec::len).min().unwrap_or(0);
+        for line in &mut lines {
+            let drop = line.len() - min_len;
+            line.drain(..drop);
+        }
+
+        Ok(Self { lines })
+    }
+
+    /// Applies one pass of the selected moving-average kind over `data`.
+    fn apply_layer(data: &[f64], ma_type: MaKind, period: usize) -> Result<Vec<f64>, String> {
+        match MovingAverage::from_kind(ma_type, data, period)? {
+            MovingAverage::SMA(mut sma) => sma.calculate(),
+            MovingAverage::EMA(mut ema) => ema.calculate(),
+            MovingAverage::WMA(mut wma) => wma.calculate(),
+            MovingAverage::VWMA(mut vwma) => vwma.calculate(),
+            MovingAverage::DEMA(mut dema) => dema.calculate(),
+            MovingAverage::TEMA(mut tema) => tema.calculate(),
+            MovingAverage::T3(mut t3) => t3.calculate(),
+            MovingAverage::HMA(mut hma) => hma.calculate(),
+            MovingAverage::ALMA(mut alma) => alma.calculate(),
+            MovingAverage::KAMA(mut kama) => kama.calculate(),
+            MovingAverage::SMMA(mut smma) => smma.calculate(),
+            MovingAverage::RMA(mut rma) => rma.calculate(),
+            MovingAverage::TRIMA(mut trima) => trima.calculate(),
+        }
+    }
+
+    /// Returns the ribbon's lines, one series per period, ordered from fastest to slowest
+    /// and right-aligned to a common length.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::indicators::ribbon::Ribbon;
+    /// use tarq::enums::MaKind;
+    ///
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    /// let ribbon = Ribbon::new(&price_data, MaKind::Sma, 2, 1, 3, 1).unwrap();
+    ///
+    /// let lines = ribbon.calculate();
+    /// println!("Ribbon lines: {:?}", lines);
+    /// ```
+    pub fn calculate(&self) -> Vec<Vec<f64>> {
+        self.lines.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::sma::Sma;
+
+    #[test]
+    fn test_ribbon_lines_match_independent_smas() {
+        let data: Vec<f64> = (1..=20).map(|x| x as f64).collect();
+
+        let ribbon = Ribbon::new(&data, MaKind::Sma, 3, 2, 3, 1).unwrap();
+        let lines = ribbon.calculate();
+
+        assert_eq!(lines.len(), 3);
+
+        let expected_periods = [3, 5, 7];
+        let mut expected: Vec<Vec<f64>> = expected_periods
+            .iter()
+            .map(|&p| Sma::new(&data, p).unwrap().calculate().unwrap())
+            .collect();
+
+        let min_len = expected.iter().map(Vec::len).min().unwrap();
+        for e in &mut expected {
+            let drop = e.len() - min_len;
+            e.drain(..drop);
+        }
+
+        assert_eq!(lines, expected);
+    }
+
+    #[test]
+    fn test_ribbon_lines_are_aligned_to_common_length() {
+        let data: Vec<f64> = (1..=20).map(|x| x as f64).collect();
+
+        let ribbon = Ribbon::new(&data, MaKind::Sma, 3, 2, 3, 1).unwrap();
+        let lines = ribbon.calculate();
+
+        let len = lines[0].len();
+        assert!(lines.iter().all(|line| line.len() == len));
+    }
+
+    #[test]
+    fn test_ribbon_smoothing_passes_matches_reapplied_sma() {
+        let data: Vec<f64> = (1..=20).map(|x| x as f64).collect();
+        let period = 3;
+
+        let ribbon = Ribbon::new(&data, MaKind::Sma, period, 0, 1, 3).unwrap();
+        let lines = ribbon.calculate();
+
+        let pass1 = Sma::new(&data, period).unwrap().calculate().unwrap();
+        let pass2 = Sma::new(&pass1, period).unwrap().calculate().unwrap();
+        let expected = Sma::new(&pass2, period).unwrap().calculate().unwrap();
+
+        assert_eq!(lines[0], expected);
+    }
+
+    #[test]
+    fn test_ribbon_invalid_count() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+
+        assert!(
+            Ribbon::new(&data, MaKind::Sma, 2, 1, 0, 1).is_err(),
+            "Ribbon should reject zero lines."
+        );
+    }
+
+    #[test]
+    fn test_ribbon_invalid_smoothing_passes() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+
+        assert!(
+            Ribbon::new(&data, MaKind::Sma, 2, 1, 2, 0).is_err(),
+            "Ribbon should reject zero smoothing passes."
+        );
+        assert!(
+            Ribbon::new(&data, MaKind::Sma, 2, 1, 2, 6).is_err(),
+            "Ribbon should reject more than 5 smoothing passes."
+        );
+    }
+
+    #[test]
+    fn test_ribbon_insufficient_data() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            Ribbon::new(&data, MaKind::Sma, 5, 1, 1, 1).is_err(),
+            "Ribbon should return an error when data is shorter than the period."
+        );
+    }
+}
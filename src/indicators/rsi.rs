@@ -0,0 +1,332 @@
+//! # Relative Strength Index (RSI) Indicator
+//!
+//! The **Relative Strength Index (RSI)** is a momentum oscillator that measures the speed
+//! and magnitude of recent price changes, expressed on a scale of `0` to `100`. It is
+//! commonly used to identify overbought (`> 70`) and oversold (`< 30`) conditions.
+//!
+//! ## Formula
+//! For each price change, split it into a gain (`max(change, 0)`) and a loss
+//! (`max(-change, 0)`). The first `period` average gain/loss is seeded as a simple average
+//! of those moves, then every subsequent average applies Wilder's smoothing:
+//! ```text
+//! avg = (prev * (period - 1) + current) / period
+//! RSI = 100 - 100 / (1 + avg_gain / avg_loss)
+//! ```
+//! `RSI` is `100` whenever `avg_loss` is `0`.
+//!
+//! ## Performance Considerations
+//! - Wilder smoothing is an O(1) recurrence, the same approach used by
+//!   [`crate::indicators::atr::Atr`]'s default smoothing.
+//! - **Iterator-based approach** ensures memory efficiency in large datasets.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::rsi::Rsi;
+//!
+//! let price_data = vec![1.0, 2.0, 1.5, 2.5, 3.0, 2.8, 3.2, 3.5, 3.1, 3.8, 4.0, 3.9, 4.2, 4.5, 4.1];
+//! let period = 5;
+//!
+//! let mut rsi = Rsi::new(&price_data, period).unwrap();
+//!
+//! let rsi_values = rsi.calculate().unwrap();
+//!
+//! println!("RSI Values: {:?}", rsi_values);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::{Indicator, Streaming};
+
+/// **The Relative Strength Index (RSI) Indicator**
+///
+/// RSI tracks the ratio of average gains to average losses over a lookback period,
+/// seeded as a simple average and then updated with Wilder's smoothing, to gauge
+/// whether an asset is overbought or oversold.
+#[derive(Clone, Debug)]
+pub struct Rsi<'a> {
+    /// Reference to the input price data.
+    data: &'a [f64],
+    /// The lookback period for computing the RSI.
+    period: usize,
+    /// Current index in the iteration process.
+    index: usize,
+    /// The previously computed average gain.
+    avg_gain: f64,
+    /// The previously computed average loss.
+    avg_loss: f64,
+    /// The previous price, used to compute the next price change.
+    prev_price: Option<f64>,
+    /// Running sum of gains seen while still warming up.
+    warmup_sum_gain: f64,
+    /// Running sum of losses seen while still warming up.
+    warmup_sum_loss: f64,
+    /// Number of price changes seen while still warming up.
+    count: usize,
+    /// Whether the warm-up average gain/loss has been seeded yet.
+    seeded: bool,
+}
+
+impl<'a> Rsi<'a> {
+    /// Creates a new instance of the Relative Strength Index (RSI).
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `period`: The lookback period for calculating the RSI.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - The `data` does not contain at least `period + 1` prices (needed to produce
+    ///   `period` price changes).
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::rsi::Rsi;
+    ///
+    /// let price_data = vec![1.0, 2.0, 1.5, 2.5, 3.0, 2.8];
+    /// let rsi = Rsi::new(&price_data, 3);
+    ///
+    /// assert!(rsi.is_ok());
+    /// ```
+    pub fn new(data: &'a [f64], period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0".to_string());
+        }
+        if data.len() < period + 1 {
+            return Err("Period cannot be greater than input data length".to_string());
+        }
+
+        Ok(Self {
+            data,
+            period,
+            index: 0,
+            avg_gain: 0.0,
+            avg_loss: 0.0,
+            prev_price: None,
+            warmup_sum_gain: 0.0,
+            warmup_sum_loss: 0.0,
+            count: 0,
+            seeded: false,
+        })
+    }
+
+    /// Creates a streaming-only instance of the RSI with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time (e.g. from a live feed)
+    /// and the full series isn't known ahead of time. Feed samples through
+    /// [`Streaming::update`]; [`Indicator::calculate`] will simply report an empty result
+    /// since there is no slice to replay.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::Streaming;
+    /// use tarq::indicators::rsi::Rsi;
+    ///
+    /// let mut rsi = Rsi::new_streaming(3).unwrap();
+    /// assert_eq!(rsi.update(1.0), None);
+    /// ```
+    pub fn new_streaming(period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0".to_string());
+        }
+
+        Ok(Self {
+            data: &[],
+            period,
+            index: 0,
+            avg_gain: 0.0,
+            avg_loss: 0.0,
+            prev_price: None,
+            warmup_sum_gain: 0.0,
+            warmup_sum_loss: 0.0,
+            count: 0,
+            seeded: false,
+        })
+    }
+
+    fn rsi_from(avg_gain: f64, avg_loss: f64) -> f64 {
+        if avg_loss == 0.0 {
+            100.0
+        } else {
+            100.0 - 100.0 / (1.0 + avg_gain / avg_loss)
+        }
+    }
+}
+
+impl Streaming for Rsi<'_> {
+    /// Advances the RSI by exactly one price.
+    ///
+    /// Returns `None` until a previous price exists and `period` price changes have
+    /// been pushed, at which point the seed average gain/loss is the simple average of
+    /// the warm-up window. Every subsequent call applies Wilder's smoothing and returns
+    /// `Some`.
+    fn update(&mut self, value: f64) -> Option<f64> {
+        let prev_price = self.prev_price.replace(value)?;
+
+        let change = value - prev_price;
+        let (gain, loss) = if change > 0.0 { (change, 0.0) } else { (0.0, -change) };
+
+        if !self.seeded {
+            self.warmup_sum_gain += gain;
+            self.warmup_sum_loss += loss;
+            self.count += 1;
+
+            if self.count < self.period {
+                return None;
+            }
+
+            self.avg_gain = self.warmup_sum_gain / self.period as f64;
+            self.avg_loss = self.warmup_sum_loss / self.period as f64;
+            self.seeded = true;
+            return Some(Self::rsi_from(self.avg_gain, self.avg_loss));
+        }
+
+        self.avg_gain = (self.avg_gain * (self.period - 1) as f64 + gain) / self.period as f64;
+        self.avg_loss = (self.avg_loss * (self.period - 1) as f64 + loss) / self.period as f64;
+
+        Some(Self::rsi_from(self.avg_gain, self.avg_loss))
+    }
+
+    /// Clears the warm-up accumulators, `avg_gain`/`avg_loss`, and the previous price,
+    /// as if freshly constructed.
+    fn reset(&mut self) {
+        self.avg_gain = 0.0;
+        self.avg_loss = 0.0;
+        self.prev_price = None;
+        self.warmup_sum_gain = 0.0;
+        self.warmup_sum_loss = 0.0;
+        self.count = 0;
+        self.seeded = false;
+    }
+}
+
+impl Iterator for Rsi<'_> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.data.len() {
+            let value = self.data[self.index];
+            self.index += 1;
+
+            if let Some(result) = self.update(value) {
+                return Some(result);
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a> Indicator<'a> for Rsi<'a> {
+    type Output = Vec<f64>;
+
+    /// Computes the Relative Strength Index (RSI) for the given data.
+    ///
+    /// Returns a vector containing the RSI values over the dataset.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::rsi::Rsi;
+    ///
+    /// let price_data = vec![1.0, 2.0, 1.5, 2.5, 3.0, 2.8];
+    /// let mut rsi = Rsi::new(&price_data, 3).unwrap();
+    ///
+    /// let rsi_values = rsi.calculate().unwrap();
+    ///
+    /// println!("RSI Values: {:?}", rsi_values);
+    /// ```
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        Ok(self.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rsi_matches_manual_wilder_smoothing() {
+        let data = vec![
+            44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08,
+            45.89, 46.03, 45.61, 46.28, 46.28, 46.00, 46.03, 46.41, 46.22, 45.64,
+        ];
+        let period = 5;
+
+        let mut rsi = Rsi::new(&data, period).unwrap();
+        let result = rsi.calculate().unwrap();
+
+        let mut changes = Vec::new();
+        for window in data.windows(2) {
+            changes.push(window[1] - window[0]);
+        }
+
+        let mut avg_gain: f64 = changes[..period].iter().map(|&c| c.max(0.0)).sum::<f64>() / period as f64;
+        let mut avg_loss: f64 = changes[..period].iter().map(|&c| (-c).max(0.0)).sum::<f64>() / period as f64;
+
+        let mut expected = vec![Rsi::rsi_from(avg_gain, avg_loss)];
+        for &change in &changes[period..] {
+            let gain = change.max(0.0);
+            let loss = (-change).max(0.0);
+            avg_gain = (avg_gain * (period - 1) as f64 + gain) / period as f64;
+            avg_loss = (avg_loss * (period - 1) as f64 + loss) / period as f64;
+            expected.push(Rsi::rsi_from(avg_gain, avg_loss));
+        }
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_rsi_all_gains_is_100() {
+        let data: Vec<f64> = (1..=10).map(|x| x as f64).collect();
+
+        let mut rsi = Rsi::new(&data, 3).unwrap();
+        let result = rsi.calculate().unwrap();
+
+        assert!(result.iter().all(|&v| (v - 100.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_rsi_invalid_period() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            Rsi::new(&data, 0).is_err(),
+            "RSI should return an error for a zero period."
+        );
+    }
+
+    #[test]
+    fn test_rsi_short_data() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            Rsi::new(&data, 5).is_err(),
+            "RSI should return an error when data is too short to produce any value."
+        );
+    }
+
+    #[test]
+    fn test_rsi_streaming_matches_slice_based() {
+        let data = vec![
+            44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08,
+            45.89, 46.03, 45.61, 46.28, 46.28,
+        ];
+        let period = 5;
+
+        let expected = Rsi::new(&data, period).unwrap().calculate().unwrap();
+
+        let mut streaming = Rsi::new_streaming(period).unwrap();
+        let streamed: Vec<f64> = data.iter().filter_map(|&value| streaming.update(value)).collect();
+
+        assert_eq!(streamed, expected, "Streaming RSI should match the slice-based calculation.");
+    }
+}
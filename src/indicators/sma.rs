@@ -1,4 +1,5 @@
-use crate::Indicator;
+use crate::circular_buffer::CircularBuffer;
+use crate::{Indicator, Streaming};
 
 #[derive(Clone, Debug)]
 pub struct Sma<'a> {
@@ -8,6 +9,8 @@ pub struct Sma<'a> {
     sum: f64,
     len: usize,
     inv_period: f64,
+    /// Sliding window of the last `period` values, used by the streaming variant.
+    window: CircularBuffer<f64>,
 }
 
 impl<'a> Sma<'a> {
@@ -21,7 +24,7 @@ impl<'a> Sma<'a> {
         }
 
         assert!(period <= data.len());
-        
+
 
         let sum = data.iter().take(period).sum();
         let inv_period = 1.0 / period as f64;
@@ -34,10 +37,67 @@ impl<'a> Sma<'a> {
             sum,
             len: data.len(),
             inv_period,
+            window: CircularBuffer::new(period),
+        })
+    }
+
+    /// Creates a streaming-only instance of the SMA with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time. Feed samples through
+    /// [`Streaming::update`]; [`Indicator::calculate`] will report an empty result since
+    /// there is no slice to replay.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    pub fn new_streaming(period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than zero.".to_string());
+        }
+
+        Ok(Self {
+            data: &[],
+            period,
+            index: 0,
+            sum: 0.0,
+            len: 0,
+            inv_period: 1.0 / period as f64,
+            window: CircularBuffer::new(period),
         })
     }
 }
 
+impl Streaming for Sma<'_> {
+    /// Advances the SMA by exactly one sample.
+    ///
+    /// Returns `None` until `period` samples have been pushed, after which every call
+    /// slides the window forward and returns `Some`.
+    fn update(&mut self, value: f64) -> Option<f64> {
+        if !self.window.is_full() {
+            self.window.push(value);
+            self.sum += value;
+
+            if !self.window.is_full() {
+                return None;
+            }
+
+            return Some(self.sum * self.inv_period);
+        }
+
+        let outgoing = *self.window.front().unwrap();
+        self.window.push(value);
+        self.sum += value - outgoing;
+
+        Some(self.sum * self.inv_period)
+    }
+
+    /// Clears the rolling window and sum back to the pre-warmup state.
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+        self.index = 0;
+    }
+}
+
 impl Iterator for Sma<'_> {
     type Item = f64;
 
@@ -121,6 +181,19 @@ mod tests {
         // Ensure calculation fails due to insufficient data
         assert!(Sma::new(&input_data, 3).is_err(), "SMA should return an error when data is shorter than the period.");
     }
+
+    #[test]
+    fn test_sma_streaming_matches_slice_based() {
+        let input_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let period = 3;
+
+        let expected = Sma::new(&input_data, period).unwrap().calculate().unwrap();
+
+        let mut streaming = Sma::new_streaming(period).unwrap();
+        let streamed: Vec<f64> = input_data.iter().filter_map(|&value| streaming.update(value)).collect();
+
+        assert_eq!(streamed, expected);
+    }
 }
 
 
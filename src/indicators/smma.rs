@@ -0,0 +1,234 @@
+//! # Smoothed Moving Average (SMMA) Indicator
+//!
+//! The **Smoothed Moving Average (SMMA)**, also known as Wilder's Moving Average, is an
+//! exponential-style moving average that weights all historical prices roughly evenly,
+//! reacting more slowly to price changes than a standard EMA. It underpins indicators
+//! such as RSI and ATR.
+//!
+//! ## Formula
+//! ```text
+//! SMMA_t = (SMMA_(t-1) * (period - 1) + Price_t) / period
+//! ```
+//! Seeded by the Simple Moving Average (SMA) of the first `period` values.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::smma::Smma;
+//!
+//! let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+//! let period = 3;
+//!
+//! let mut smma = Smma::new(&price_data, period).unwrap();
+//! let smma_values = smma.calculate().unwrap();
+//!
+//! println!("SMMA Values: {:?}", smma_values);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::{Indicator, Streaming};
+
+/// **The Smoothed Moving Average (SMMA) Indicator**
+///
+/// SMMA (Wilder's Moving Average) smooths price data by folding in each new sample
+/// with weight `1 / period` against the running average, reacting more slowly than
+/// a standard EMA over the same period.
+#[derive(Clone, Debug)]
+pub struct Smma<'a> {
+    /// Reference to the input price data.
+    data: &'a [f64],
+    /// The lookback period for computing the SMMA.
+    period: usize,
+    /// Current index in the iteration process.
+    index: usize,
+    /// The previously computed SMMA value.
+    prev_smma: f64,
+    /// Running sum of the samples seen while still warming up.
+    warmup_sum: f64,
+    /// Number of samples pushed through [`Streaming::update`] so far.
+    count: usize,
+}
+
+impl<'a> Smma<'a> {
+    /// Creates a new instance of the Smoothed Moving Average (SMMA).
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `period`: The lookback period for calculating the SMMA.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - The `data` length is shorter than the `period`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::*;
+    /// use tarq::indicators::smma::Smma;
+    ///
+    /// let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let smma = Smma::new(&price_data, 3);
+    ///
+    /// assert!(smma.is_ok());
+    /// ```
+    pub fn new(data: &'a [f64], period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0".to_string());
+        }
+        if data.len() < period {
+            return Err("Period cannot be greater than input data length".to_string());
+        }
+
+        Ok(Self {
+            data,
+            period,
+            index: 0,
+            prev_smma: 0.0,
+            warmup_sum: 0.0,
+            count: 0,
+        })
+    }
+
+    /// Creates a streaming-only instance of the SMMA with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time. Feed samples through
+    /// [`Streaming::update`]; [`Indicator::calculate`] will report an empty result since
+    /// there is no slice to replay.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    pub fn new_streaming(period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0".to_string());
+        }
+
+        Ok(Self {
+            data: &[],
+            period,
+            index: 0,
+            prev_smma: 0.0,
+            warmup_sum: 0.0,
+            count: 0,
+        })
+    }
+}
+
+impl Streaming for Smma<'_> {
+    /// Advances the SMMA by exactly one sample.
+    ///
+    /// Returns `None` until `period` samples have been pushed, at which point the seed
+    /// value is the simple average of the warm-up window. Every subsequent call applies
+    /// the SMMA recurrence and returns `Some`.
+    fn update(&mut self, value: f64) -> Option<f64> {
+        if self.count < self.period {
+            self.warmup_sum += value;
+            self.count += 1;
+
+            if self.count == self.period {
+                self.prev_smma = self.warmup_sum / self.period as f64;
+                return Some(self.prev_smma);
+            }
+
+            return None;
+        }
+
+        self.prev_smma = (self.prev_smma * (self.period - 1) as f64 + value) / self.period as f64;
+        Some(self.prev_smma)
+    }
+
+    /// Clears the warm-up accumulator and `prev_smma`, as if freshly constructed.
+    fn reset(&mut self) {
+        self.prev_smma = 0.0;
+        self.warmup_sum = 0.0;
+        self.count = 0;
+    }
+}
+
+impl Iterator for Smma<'_> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.data.len() {
+            let value = self.data[self.index];
+            self.index += 1;
+
+            if let Some(result) = self.update(value) {
+                return Some(result);
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a> Indicator<'a> for Smma<'a> {
+    type Output = Vec<f64>;
+
+    /// Computes the Smoothed Moving Average (SMMA) for the given data.
+    ///
+    /// Returns a vector containing the SMMA values over the dataset.
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        Ok(self.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smma_valid() {
+        let input_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let period = 3;
+
+        let mut smma = Smma::new(&input_data, period).unwrap();
+        let result = smma.calculate().unwrap();
+
+        // Seed: SMA of the first 3 values = 2.0
+        let mut expected = vec![2.0];
+        let mut prev = 2.0;
+        for &value in &input_data[period..] {
+            prev = (prev * (period - 1) as f64 + value) / period as f64;
+            expected.push(prev);
+        }
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_smma_invalid_input() {
+        let input_data = vec![];
+
+        assert!(
+            Smma::new(&input_data, 3).is_err(),
+            "SMMA should return an error for empty input."
+        );
+    }
+
+    #[test]
+    fn test_smma_short_data() {
+        let input_data = vec![1.0, 2.0];
+
+        assert!(
+            Smma::new(&input_data, 3).is_err(),
+            "SMMA should return an error when data is shorter than the period."
+        );
+    }
+
+    #[test]
+    fn test_smma_streaming_matches_slice_based() {
+        let input_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let period = 3;
+
+        let expected = Smma::new(&input_data, period).unwrap().calculate().unwrap();
+
+        let mut streaming = Smma::new_streaming(period).unwrap();
+        let streamed: Vec<f64> = input_data.iter().filter_map(|&value| streaming.update(value)).collect();
+
+        assert_eq!(streamed, expected);
+    }
+}
@@ -0,0 +1,211 @@
+//! # Smoothed Normalized ATR (SNATR) Indicator
+//!
+//! Raw [`crate::indicators::atr::Atr`] is unbounded, which makes it hard to compare
+//! volatility across instruments with different price scales. **SNATR** fixes this by
+//! rescaling ATR into a bounded, oscillator-style range before smoothing it.
+//!
+//! ## Calculation
+//! 1. Compute ATR (Wilder-smoothed) over `period`.
+//! 2. Normalize each ATR value against the rolling min/max of the last `period` ATR values:
+//!    ```text
+//!    normalized_t = (atr_t - min(atr, period)) / (max(atr, period) - min(atr, period)) * scale
+//!    ```
+//!    If the rolling range is `0` (a flat volatility regime), the previous normalized value
+//!    is reused (or `0.0` if there is no previous value yet).
+//! 3. Smooth the normalized series with the chosen [`Smooth`] mode over the same `period`.
+//!
+//! `scale` is commonly `100.0`, making SNATR read like a bounded oscillator. Each
+//! normalization window includes the current ATR value, so every normalized value lies in
+//! `0..=scale`, and the smoothing stage (an average of those values) stays in that range.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::snatr::Snatr;
+//! use tarq::indicators::atr::Smooth;
+//!
+//! let high = vec![2.0, 3.5, 4.0, 5.5, 6.0, 7.5, 8.0, 9.5, 10.0, 11.5];
+//! let low = vec![1.0, 2.5, 3.0, 4.5, 5.0, 6.5, 7.0, 8.5, 9.0, 10.5];
+//! let close = vec![1.5, 3.0, 3.5, 5.0, 5.5, 7.0, 7.5, 9.0, 9.5, 11.0];
+//! let period = 3;
+//!
+//! let mut snatr = Snatr::new(&high, &low, &close, period, Smooth::Ema, 100.0).unwrap();
+//! let snatr_values = snatr.calculate().unwrap();
+//!
+//! println!("SNATR Values: {:?}", snatr_values);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::indicators::atr::{smooth_series, Atr, Smooth};
+use crate::Indicator;
+
+/// **The Smoothed Normalized ATR (SNATR) Indicator**
+///
+/// SNATR rescales ATR into a rolling `0..=scale` range and then smooths the result,
+/// producing a bounded, cross-instrument-comparable volatility oscillator.
+#[derive(Clone, Debug)]
+pub struct Snatr {
+    /// The precomputed SNATR values.
+    values: Vec<f64>,
+    /// Current index in the iteration process.
+    index: usize,
+}
+
+impl Snatr {
+    /// Creates a new instance of the Smoothed Normalized ATR (SNATR).
+    ///
+    /// # Arguments
+    /// - `high`, `low`, `close`: OHLC price data (all the same length).
+    /// - `period`: The lookback period used for the ATR, the rolling min/max
+    ///   normalization window, and the final smoothing stage.
+    /// - `smooth`: Which [`Smooth`] mode to apply to the normalized series.
+    /// - `scale`: The target range for the normalized series (commonly `100.0`).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - There isn't enough data to produce at least one ATR value followed by a full
+    ///   rolling normalization window.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::indicators::snatr::Snatr;
+    /// use tarq::indicators::atr::Smooth;
+    ///
+    /// let high = vec![2.0, 3.5, 4.0, 5.5, 6.0, 7.5, 8.0, 9.5, 10.0, 11.5];
+    /// let low = vec![1.0, 2.5, 3.0, 4.5, 5.0, 6.5, 7.0, 8.5, 9.0, 10.5];
+    /// let close = vec![1.5, 3.0, 3.5, 5.0, 5.5, 7.0, 7.5, 9.0, 9.5, 11.0];
+    ///
+    /// let snatr = Snatr::new(&high, &low, &close, 3, Smooth::Wilder, 100.0);
+    /// assert!(snatr.is_ok());
+    /// ```
+    pub fn new(high: &[f64], low: &[f64], close: &[f64], period: usize, smooth: Smooth, scale: f64) -> Result<Self, String> {
+        let atr_values = Atr::new(high, low, close, period)?.calculate()?;
+
+        if atr_values.len() < period {
+            return Err("Period cannot be greater than input data length".to_string());
+        }
+
+        let mut normalized = Vec::with_capacity(atr_values.len() - period + 1);
+        let mut prev_norm = 0.0;
+
+        for i in (period - 1)..atr_values.len() {
+            let window = &atr_values[i + 1 - period..=i];
+            let min = window.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = window.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let range = max - min;
+
+            let value = if range == 0.0 {
+                prev_norm
+            } else {
+                (atr_values[i] - min) / range * scale
+            };
+
+            prev_norm = value;
+            normalized.push(value);
+        }
+
+        let values = smooth_series(&normalized, period, smooth)?;
+
+        Ok(Self { values, index: 0 })
+    }
+}
+
+impl Iterator for Snatr {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.values.get(self.index).copied()?;
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.values.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> Indicator<'a> for Snatr {
+    type Output = Vec<f64>;
+
+    /// Computes the Smoothed Normalized ATR (SNATR) for the given data.
+    ///
+    /// Returns a vector containing the SNATR values over the dataset.
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        Ok(self.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_ohlc() -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        let high = vec![
+            2.0, 3.5, 4.0, 5.5, 6.0, 7.5, 8.0, 9.5, 10.0, 11.5, 12.0, 13.5, 14.0, 15.5,
+        ];
+        let low = vec![
+            1.0, 2.5, 3.0, 4.5, 5.0, 6.5, 7.0, 8.5, 9.0, 10.5, 11.0, 12.5, 13.0, 14.5,
+        ];
+        let close = vec![
+            1.5, 3.0, 3.5, 5.0, 5.5, 7.0, 7.5, 9.0, 9.5, 11.0, 11.5, 13.0, 13.5, 15.0,
+        ];
+        (high, low, close)
+    }
+
+    #[test]
+    fn test_snatr_bounded_by_scale() {
+        let (high, low, close) = sample_ohlc();
+        let period = 3;
+        let scale = 100.0;
+
+        let mut snatr = Snatr::new(&high, &low, &close, period, Smooth::Wilder, scale).unwrap();
+        let result = snatr.calculate().unwrap();
+
+        assert!(!result.is_empty());
+        for &value in &result {
+            assert!((-1e-6..=scale + 1e-6).contains(&value), "SNATR value {} outside expected range", value);
+        }
+    }
+
+    #[test]
+    fn test_snatr_constant_volatility_is_flat() {
+        // A perfectly linear OHLC series has constant true range, so ATR is flat and the
+        // rolling range used for normalization is always 0.
+        let high: Vec<f64> = (0..20).map(|i| 2.0 + i as f64).collect();
+        let low: Vec<f64> = (0..20).map(|i| 1.0 + i as f64).collect();
+        let close: Vec<f64> = (0..20).map(|i| 1.5 + i as f64).collect();
+        let period = 3;
+
+        let mut snatr = Snatr::new(&high, &low, &close, period, Smooth::Sma, 100.0).unwrap();
+        let result = snatr.calculate().unwrap();
+
+        for &value in &result {
+            assert!((value - 0.0).abs() < 1e-9, "SNATR should stay at 0 when ATR never varies");
+        }
+    }
+
+    #[test]
+    fn test_snatr_invalid_period() {
+        let (high, low, close) = sample_ohlc();
+
+        assert!(
+            Snatr::new(&high, &low, &close, 0, Smooth::Wilder, 100.0).is_err(),
+            "SNATR should return an error for a zero period."
+        );
+    }
+
+    #[test]
+    fn test_snatr_short_data() {
+        let high = vec![2.0, 3.0];
+        let low = vec![1.0, 2.0];
+        let close = vec![1.5, 2.5];
+
+        assert!(
+            Snatr::new(&high, &low, &close, 5, Smooth::Wilder, 100.0).is_err(),
+            "SNATR should return an error when data is too short."
+        );
+    }
+}
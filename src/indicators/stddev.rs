@@ -5,17 +5,29 @@
 //! how much prices deviate from the average over a given period.
 //!
 //! ## Formula
-//! The standard deviation is computed as follows:
-//!
-//! ```text
-//! Variance = (Σ(x²) / (N - ddof)) - (Mean²)
-//! StdDev = √Variance
-//! ```
+//! Computing variance as `Σ(x²)/(N - ddof) - mean²` subtracts two large, nearly-equal
+//! quantities and suffers catastrophic cancellation on price series with large absolute
+//! levels, occasionally producing a tiny negative variance whose square root is `NaN`.
+//! Instead, `StdDev` maintains a running `mean` and sum of squared deviations (`M2`) over
+//! the window, updated via a sliding-window Welford scheme (the same approach used by
+//! [`crate::indicators::bbands::BBands`]):
+//! - **Initial window**: `mean`/`M2` are computed directly over the first `period` values.
+//! - **Every subsequent slide** (removing `x_out`, admitting `x_in`, count unchanged): the
+//!   exact replace recurrence corrects both in place rather than recomputing from scratch:
+//!   ```text
+//!   new_mean = mean + (x_in - x_out) / N
+//!   M2 += (x_in - x_out) * (x_in - new_mean + x_out - mean)
+//!   mean = new_mean
+//!   Variance = max(0, M2 / (N - ddof))
+//!   StdDev = √Variance
+//!   ```
+//! Because `M2` is corrected by a small delta every step rather than re-derived from a sum
+//! of squares, cancellation never accumulates the way it would with a naive `Σ(x²)`
+//! accumulator, even on a long series sitting on a large baseline.
 //!
 //! Where:
 //! - **x** = individual data points
 //! - **N** = number of observations (period)
-//! - **Mean** = Simple Moving Average (SMA) of the dataset
 //! - **ddof (Delta Degrees of Freedom)** = Optional degrees of freedom (default is 0)
 //!
 //! ## Advantages of StdDev in Technical Analysis
@@ -24,7 +36,8 @@
 //! - **Risk Management** → Helps identify stable or turbulent periods.
 //!
 //! ## Performance Considerations
-//! - Uses a **rolling sum of squares approach**, optimizing standard deviation calculations.
+//! - Uses a **sliding-window Welford mean/M2 update**, optimizing standard deviation
+//!   calculations while staying numerically stable at large price levels.
 //! - Uses **iterator-based computation**, making it efficient for real-time data processing.
 //!
 //! ## Example Usage
@@ -44,13 +57,14 @@
 //!
 //! ## Struct Definition
 
-use crate::Indicator;
-use crate::indicators::sma::Sma; // Assuming Sma is implemented
+use crate::candle::{project, Candle, Source};
+use crate::circular_buffer::CircularBuffer;
+use crate::{Indicator, Streaming};
 
 /// **The Standard Deviation (StdDev) Indicator**
 ///
-/// The `StdDev` struct calculates the rolling standard deviation of a dataset over 
-/// a specified period. It measures the price dispersion around the mean and is often 
+/// The `StdDev` struct calculates the rolling standard deviation of a dataset over
+/// a specified period. It measures the price dispersion around the mean and is often
 /// used in volatility analysis and indicators like Bollinger Bands.
 #[derive(Clone, Debug)]
 pub struct StdDev<'a> {
@@ -60,12 +74,14 @@ pub struct StdDev<'a> {
     period: usize,
     /// Current index in the iteration process.
     index: usize,
-    /// Simple Moving Average (SMA) instance used for mean calculation.
-    sma: Sma<'a>,
-    /// Rolling sum of squared values used for variance calculation.
-    sum_sq: f64,
+    /// Running mean of the current window.
+    mean: f64,
+    /// Running sum of squared deviations from `mean` over the current window.
+    m2: f64,
     /// Degrees of freedom adjustment (default is 0).
     ddof: usize,
+    /// Ring buffer of the last `period` values, used by the streaming variant.
+    window: CircularBuffer<f64>,
 }
 
 impl<'a> StdDev<'a> {
@@ -99,17 +115,128 @@ impl<'a> StdDev<'a> {
             return Err("Period must be set to a number greater than 0".to_string());
         }
 
-        let sma = Sma::new(data, period).unwrap();
-
         Ok(Self {
             data,
             period,
             index: 0,
-            sma,
-            sum_sq: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            ddof,
+            window: CircularBuffer::new(period),
+        })
+    }
+
+    /// Creates a streaming-only instance of the StdDev with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time (e.g. from a live feed)
+    /// and the full series isn't known ahead of time. Feed samples through
+    /// [`Streaming::update`]; [`Indicator::calculate`] will simply report an empty result
+    /// since there is no slice to replay. Internally it owns a fixed-capacity ring buffer
+    /// of the last `period` values instead of borrowing a slice.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::Streaming;
+    /// use tarq::indicators::stddev::StdDev;
+    ///
+    /// let mut stddev = StdDev::new_streaming(3, 0).unwrap();
+    /// assert_eq!(stddev.update(1.0), None);
+    /// assert_eq!(stddev.update(2.0), None);
+    /// assert!(stddev.update(3.0).is_some());
+    /// ```
+    pub fn new_streaming(period: usize, ddof: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be set to a number greater than 0".to_string());
+        }
+
+        Ok(Self {
+            data: &[],
+            period,
+            index: 0,
+            mean: 0.0,
+            m2: 0.0,
             ddof,
+            window: CircularBuffer::new(period),
         })
     }
+
+    /// Computes the rolling standard deviation of a chosen [`Source`] projected out of a
+    /// slice of OHLCV [`Candle`]s, e.g. typical price (`Source::HLC3`) instead of a plain
+    /// close.
+    ///
+    /// Since the projected prices are only owned for the duration of this call, this
+    /// computes and returns the final values directly rather than an [`StdDev`] instance
+    /// borrowing from them (which couldn't outlive this function call).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - `candles` is shorter than the `period`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::candle::{Candle, Source};
+    /// use tarq::indicators::stddev::StdDev;
+    ///
+    /// let candles = vec![
+    ///     Candle { open: 1.0, high: 2.0, low: 0.5, close: 1.5, volume: 100.0 },
+    ///     Candle { open: 1.5, high: 2.5, low: 1.0, close: 2.0, volume: 120.0 },
+    ///     Candle { open: 2.0, high: 3.0, low: 1.5, close: 2.5, volume: 90.0 },
+    /// ];
+    ///
+    /// let result = StdDev::from_candles(&candles, 3, 0, Source::HLC3);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn from_candles(candles: &[Candle], period: usize, ddof: usize, source: Source) -> Result<Vec<f64>, String> {
+        let prices = project(candles, source);
+        StdDev::new(&prices, period, ddof)?.calculate()
+    }
+
+    /// Computes `max(0, M2 / (N - ddof))`, then takes its square root.
+    fn std_dev_from(&self) -> f64 {
+        let variance = self.m2 / (self.period - self.ddof) as f64;
+        variance.max(0.0).sqrt()
+    }
+}
+
+impl Streaming for StdDev<'_> {
+    /// Advances the standard deviation by exactly one sample.
+    ///
+    /// Returns `None` until `period` samples have been pushed into the ring buffer, after
+    /// which every call slides the window forward and returns `Some`.
+    fn update(&mut self, value: f64) -> Option<f64> {
+        if !self.window.is_full() {
+            self.window.push(value);
+
+            if !self.window.is_full() {
+                return None;
+            }
+
+            self.mean = self.window.iter().sum::<f64>() / self.period as f64;
+            self.m2 = self.window.iter().map(|&x| (x - self.mean) * (x - self.mean)).sum();
+        } else {
+            let outgoing = *self.window.front().unwrap();
+            self.window.push(value);
+            self.index += 1;
+
+            let new_mean = self.mean + (value - outgoing) / self.period as f64;
+            self.m2 += (value - outgoing) * (value - new_mean + outgoing - self.mean);
+            self.mean = new_mean;
+        }
+
+        Some(self.std_dev_from())
+    }
+
+    /// Clears the ring buffer, the running mean/M2, and the index back to the pre-warmup state.
+    fn reset(&mut self) {
+        self.window.clear();
+        self.mean = 0.0;
+        self.m2 = 0.0;
+        self.index = 0;
+    }
 }
 
 impl Iterator for StdDev<'_> {
@@ -121,22 +248,19 @@ impl Iterator for StdDev<'_> {
         }
 
         if self.index == 0 {
-            // Compute the initial sum of squares for the first `period` values
-            self.sum_sq = self.data[..self.period].iter().map(|&x| x * x).sum::<f64>();
-        } else if self.index + self.period <= self.data.len() {
-            // Rolling update: Remove outgoing value and add new incoming value
-            let outgoing_index = self.index - 1;
-            let incoming_index = self.index + self.period - 1;
-
-            self.sum_sq += self.data[incoming_index] * self.data[incoming_index]
-                - self.data[outgoing_index] * self.data[outgoing_index];
-        }
+            let window = &self.data[..self.period];
+            self.mean = window.iter().sum::<f64>() / self.period as f64;
+            self.m2 = window.iter().map(|&x| (x - self.mean) * (x - self.mean)).sum();
+        } else {
+            let outgoing = self.data[self.index - 1];
+            let incoming = self.data[self.index + self.period - 1];
 
-        let mean = self.sma.next()?;
+            let new_mean = self.mean + (incoming - outgoing) / self.period as f64;
+            self.m2 += (incoming - outgoing) * (incoming - new_mean + outgoing - self.mean);
+            self.mean = new_mean;
+        }
 
-        // Compute variance and standard deviation
-        let variance = (self.sum_sq / (self.period - self.ddof) as f64) - (mean * mean);
-        let std_dev = variance.sqrt();
+        let std_dev = self.std_dev_from();
 
         self.index += 1;
         Some(std_dev)
@@ -189,6 +313,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_stddev_stable_at_large_price_levels() {
+        // A tight cluster of values sitting on a very large baseline (e.g. BTC at 60000+)
+        // is exactly the case that causes a naive Σx² accumulator to produce a tiny
+        // negative variance, whose sqrt is NaN.
+        let data = vec![
+            60000.01, 60000.02, 60000.03, 60000.02, 60000.01, 60000.02, 60000.03, 60000.04,
+        ];
+        let period = 4;
+
+        let result = StdDev::new(&data, period, 0).unwrap().calculate().unwrap();
+
+        assert!(result.iter().all(|v| v.is_finite() && *v >= 0.0), "StdDev produced a non-finite or negative value: {:?}", result);
+    }
+
     #[test]
     fn test_stddev_invalid_input() {
         let data = vec![10.0, 12.0, 23.0, 23.0];
@@ -206,4 +345,62 @@ mod tests {
 
         assert!(std_dev.is_err(), "Expected error for too short data");
     }
+
+    #[test]
+    fn test_stddev_streaming_matches_slice_based() {
+        let data = vec![10.0, 12.0, 23.0, 23.0, 16.0, 20.0, 25.0, 30.0, 28.0, 26.0];
+        let period = 3;
+
+        let expected = StdDev::new(&data, period, 0).unwrap().calculate().unwrap();
+
+        let mut streaming = StdDev::new_streaming(period, 0).unwrap();
+        let streamed: Vec<f64> = data.iter().filter_map(|&value| streaming.update(value)).collect();
+
+        assert_eq!(streamed.len(), expected.len());
+        for (r, e) in streamed.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_stddev_from_candles_matches_projected_source() {
+        let candles = vec![
+            Candle { open: 9.0, high: 11.0, low: 8.0, close: 10.0, volume: 100.0 },
+            Candle { open: 10.0, high: 13.0, low: 9.0, close: 12.0, volume: 110.0 },
+            Candle { open: 12.0, high: 24.0, low: 11.0, close: 23.0, volume: 90.0 },
+            Candle { open: 23.0, high: 25.0, low: 22.0, close: 23.0, volume: 95.0 },
+            Candle { open: 23.0, high: 17.0, low: 15.0, close: 16.0, volume: 80.0 },
+        ];
+        let period = 3;
+
+        let expected_prices: Vec<f64> = candles.iter().map(|c| (c.high + c.low + c.close) / 3.0).collect();
+        let expected = StdDev::new(&expected_prices, period, 0).unwrap().calculate().unwrap();
+
+        let result = StdDev::from_candles(&candles, period, 0, Source::HLC3).unwrap();
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_stddev_from_candles_short_data() {
+        let candles = vec![Candle { open: 1.0, high: 2.0, low: 0.5, close: 1.5, volume: 10.0 }];
+
+        assert!(StdDev::from_candles(&candles, 3, 0, Source::Close).is_err());
+    }
+
+    #[test]
+    fn test_stddev_reset_clears_state() {
+        let mut streaming = StdDev::new_streaming(3, 0).unwrap();
+        assert!(streaming.update(1.0).is_none());
+        assert!(streaming.update(2.0).is_none());
+        assert!(streaming.update(3.0).is_some());
+
+        streaming.reset();
+        assert!(streaming.update(4.0).is_none());
+        assert!(streaming.update(5.0).is_none());
+        assert!(streaming.update(6.0).is_some());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,261 @@
+//! # Tillson T3 Moving Average Indicator
+//!
+//! **T3** is a further lag-reduced relative of [`crate::indicators::dema::Dema`] and
+//! [`crate::indicators::tema::Tema`] in the same EMA-family of smoothers. Where DEMA applies
+//! the "generalized DEMA" operator once and TEMA chains three plain EMAs, T3 applies the
+//! generalized DEMA operator three times in a row:
+//!
+//! ```text
+//! GD(x) = EMA(x) * (1 + v) - EMA(EMA(x)) * v
+//! T3    = GD(GD(GD(x)))
+//! ```
+//!
+//! Expanding `GD(GD(GD(x)))` in terms of six chained EMAs of the input gives:
+//! ```text
+//! T3 = c4*EMA3 + c3*EMA4 + c2*EMA5 + c1*EMA6
+//! c1 = -v^3
+//! c2 = 3*v^2 + 3*v^3
+//! c3 = -6*v^2 - 3*v - 3*v^3
+//! c4 = 1 + 3*v + v^3 + 3*v^2
+//! ```
+//! Where `EMA1..EMA6` are six EMAs chained end-to-end (`EMA2` is the EMA of `EMA1`, etc.),
+//! each one seeded with the SMA of its own first `period` inputs, the same convention used
+//! by [`crate::indicators::dema::Dema`] and [`crate::indicators::tema::Tema`].
+//!
+//! `v` is the "volume factor"; Tillson's original paper recommends `v = 0.7`.
+//!
+//! ## Performance Considerations
+//! - Each of the six chained EMAs drops `period - 1` leading samples, so T3 consumes
+//!   `6 * (period - 1) + 1` samples before producing its first value. [`T3::warmup_len`]
+//!   reports this offset so callers can align T3's output back onto the original series.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::t3::T3;
+//!
+//! let price_data: Vec<f64> = (1..=40).map(|x| x as f64).collect();
+//! let period = 3;
+//! let v = 0.7;
+//!
+//! let mut t3 = T3::new(&price_data, period, v).unwrap();
+//! let t3_values = t3.calculate().unwrap();
+//!
+//! println!("T3 Values: {:?}", t3_values);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::indicators::ema::Ema;
+use crate::Indicator;
+
+/// **The Tillson T3 Moving Average Indicator**
+///
+/// T3 chains six EMAs of the input and combines the last four of them with binomial
+/// coefficients derived from the volume factor `v`, producing a smoother, lower-lag
+/// average than [`crate::indicators::tema::Tema`].
+#[derive(Clone, Debug)]
+pub struct T3 {
+    /// The precomputed T3 values.
+    values: Vec<f64>,
+    /// Current index in the iteration process.
+    index: usize,
+    /// Number of leading input samples consumed before the first T3 value.
+    warmup_len: usize,
+}
+
+impl T3 {
+    /// Creates a new instance of the Tillson T3 Moving Average.
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `period`: The lookback period used by each of the six chained EMAs.
+    /// - `v`: The volume factor controlling how aggressively lag is reduced. Tillson's
+    ///   original paper recommends `v = 0.7`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - The `data` is too short to produce six chained EMAs (i.e. shorter than
+    ///   `6 * (period - 1) + 1`).
+    /// - `v` is outside the `[0.0, 1.0]` range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::indicators::t3::T3;
+    ///
+    /// let price_data: Vec<f64> = (1..=40).map(|x| x as f64).collect();
+    /// let t3 = T3::new(&price_data, 3, 0.7);
+    ///
+    /// assert!(t3.is_ok());
+    /// ```
+    pub fn new(data: &[f64], period: usize, v: f64) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0".to_string());
+        }
+        if !(0.0..=1.0).contains(&v) {
+            return Err("Volume factor must be between 0.0 and 1.0".to_string());
+        }
+
+        let warmup_len = 6 * (period - 1) + 1;
+        if data.len() < warmup_len {
+            return Err("Period cannot be greater than input data length".to_string());
+        }
+
+        let ema1 = Ema::new(data, period)?.calculate()?;
+        let ema2 = Ema::new(&ema1, period)?.calculate()?;
+        let ema3 = Ema::new(&ema2, period)?.calculate()?;
+        let ema4 = Ema::new(&ema3, period)?.calculate()?;
+        let ema5 = Ema::new(&ema4, period)?.calculate()?;
+        let ema6 = Ema::new(&ema5, period)?.calculate()?;
+
+        let c1 = -v.powi(3);
+        let c2 = 3.0 * v.powi(2) + 3.0 * v.powi(3);
+        let c3 = -6.0 * v.powi(2) - 3.0 * v - 3.0 * v.powi(3);
+        let c4 = 1.0 + 3.0 * v + v.powi(3) + 3.0 * v.powi(2);
+
+        let len = ema6.len();
+        let offset3 = ema3.len() - len;
+        let offset4 = ema4.len() - len;
+        let offset5 = ema5.len() - len;
+
+        let mut values = Vec::with_capacity(len);
+        for i in 0..len {
+            let value = c4 * ema3[i + offset3] + c3 * ema4[i + offset4] + c2 * ema5[i + offset5] + c1 * ema6[i];
+            values.push(value);
+        }
+
+        Ok(Self {
+            values,
+            index: 0,
+            warmup_len,
+        })
+    }
+
+    /// Returns the number of leading input samples consumed before the first T3 value.
+    ///
+    /// Equal to `6 * (period - 1) + 1`. Useful for callers that need to align T3's
+    /// (shorter) output back onto the original, unsmoothed series.
+    pub fn warmup_len(&self) -> usize {
+        self.warmup_len
+    }
+}
+
+impl Iterator for T3 {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.values.get(self.index).copied()?;
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.values.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> Indicator<'a> for T3 {
+    type Output = Vec<f64>;
+
+    /// Computes the Tillson T3 values for the given data.
+    ///
+    /// Returns a vector containing the T3 values over the dataset.
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        Ok(self.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_t3_matches_manual_gd_chain() {
+        let data: Vec<f64> = (1..=40).map(|x| (x as f64 * 1.37).sin() * 10.0 + x as f64).collect();
+        let period = 3;
+        let v = 0.7;
+
+        let mut t3 = T3::new(&data, period, v).unwrap();
+        let result = t3.calculate().unwrap();
+
+        let ema1 = Ema::new(&data, period).unwrap().calculate().unwrap();
+        let ema2 = Ema::new(&ema1, period).unwrap().calculate().unwrap();
+        let ema3 = Ema::new(&ema2, period).unwrap().calculate().unwrap();
+        let ema4 = Ema::new(&ema3, period).unwrap().calculate().unwrap();
+        let ema5 = Ema::new(&ema4, period).unwrap().calculate().unwrap();
+        let ema6 = Ema::new(&ema5, period).unwrap().calculate().unwrap();
+
+        // Independently re-derive T3's weights from GD(x) = (1 + v)*EMA(x) - v*EMA(EMA(x))
+        // applied three times in a row, rather than reusing T3::new's already-simplified c1..c4
+        // constants: treating "one more EMA stage" as an operator S, GD = (1 + v)*S - v*S^2, so
+        // GD(GD(GD(x))) is the operator `A = (1 + v)*S - v*S^2` cubed. Expanding `A^3` via
+        // repeated convolution (instead of the pre-simplified binomial formula) gives the
+        // weights over S^3..S^6, i.e. over ema3..ema6.
+        let mut weights = vec![1.0];
+        for _ in 0..3 {
+            let mut next = vec![0.0; weights.len() + 1];
+            for (i, &w) in weights.iter().enumerate() {
+                next[i] += w * (1.0 + v);
+                next[i + 1] += w * -v;
+            }
+            weights = next;
+        }
+
+        let emas = [&ema3, &ema4, &ema5, &ema6];
+        let len = ema6.len();
+        let expected: Vec<f64> = (0..len)
+            .map(|i| weights.iter().zip(emas.iter()).map(|(&w, ema)| w * ema[i + (ema.len() - len)]).sum())
+            .collect();
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_t3_warmup_len() {
+        let data: Vec<f64> = (1..=40).map(|x| x as f64).collect();
+        let period = 3;
+
+        let t3 = T3::new(&data, period, 0.7).unwrap();
+
+        assert_eq!(t3.warmup_len(), 6 * (period - 1) + 1);
+    }
+
+    #[test]
+    fn test_t3_invalid_period() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            T3::new(&data, 0, 0.7).is_err(),
+            "T3 should return an error for a zero period."
+        );
+    }
+
+    #[test]
+    fn test_t3_short_data() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            T3::new(&data, 5, 0.7).is_err(),
+            "T3 should return an error when data is too short for six chained EMAs."
+        );
+    }
+
+    #[test]
+    fn test_t3_invalid_volume_factor() {
+        let data: Vec<f64> = (1..=40).map(|x| x as f64).collect();
+
+        assert!(
+            T3::new(&data, 3, -0.1).is_err(),
+            "T3 should return an error for a negative volume factor."
+        );
+        assert!(
+            T3::new(&data, 3, 1.1).is_err(),
+            "T3 should return an error for a volume factor above 1.0."
+        );
+    }
+}
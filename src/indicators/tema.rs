@@ -1,8 +1,8 @@
-use crate::Indicator;
+use crate::{Indicator, Streaming};
 
 /// **The Triple Exponential Moving Average (TEMA) Indicator**
 ///
-/// TEMA is a smoothed version of the Exponential Moving Average (EMA), reducing lag 
+/// TEMA is a smoothed version of the Exponential Moving Average (EMA), reducing lag
 /// significantly by applying EMA three times and using the formula:
 ///
 /// `TEMA = (3 * EMA1) - (3 * EMA2) + EMA3`
@@ -22,8 +22,10 @@ pub struct Tema<'a> {
     prev_ema3: f64,
     /// The smoothing factor used in the EMA formula.
     smoothing: f64,
-    /// Lenght of iterator when initialized.
-    len: usize,
+    /// Raw samples collected while warming up `prev_ema1`/`prev_ema2`/`prev_ema3`.
+    warmup: Vec<f64>,
+    /// Whether the EMA accumulators have been seeded via the warm-up window.
+    seeded: bool,
 }
 
 impl<'a> Tema<'a> {
@@ -53,81 +55,121 @@ impl<'a> Tema<'a> {
             prev_ema2: 0.0,
             prev_ema3: 0.0,
             smoothing: 2.0 / (period as f64 + 1.0),
-            len: data.len(),
+            warmup: Vec::with_capacity(3 * period - 2),
+            seeded: false,
         })
     }
-}
-
-impl Iterator for Tema<'_> {
-    type Item = f64;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index + (3 * self.period - 3) >= self.data.len() {
-            return None;
+    /// Creates a streaming-only instance of the TEMA with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time and the full series isn't
+    /// known ahead of time. Feed samples through [`Streaming::update`]; [`Indicator::calculate`]
+    /// will report an empty result since there is no slice to replay.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    pub fn new_streaming(period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0".to_string());
         }
 
-        if self.index == 0 {
+        Ok(Self {
+            data: &[],
+            index: 0,
+            period,
+            prev_ema1: 0.0,
+            prev_ema2: 0.0,
+            prev_ema3: 0.0,
+            smoothing: 2.0 / (period as f64 + 1.0),
+            warmup: Vec::with_capacity(3 * period - 2),
+            seeded: false,
+        })
+    }
+}
+
+impl Streaming for Tema<'_> {
+    /// Advances the TEMA by exactly one sample.
+    ///
+    /// Returns `None` until `3 * period - 2` samples have been pushed, which is the same
+    /// warm-up window the slice-based path uses to seed `prev_ema1`/`prev_ema2`/`prev_ema3`.
+    /// After that, every call applies the three EMA recurrences and returns
+    /// `Some(3 * ema1 - 3 * ema2 + ema3)`.
+    fn update(&mut self, value: f64) -> Option<f64> {
+        if !self.seeded {
+            self.warmup.push(value);
+            let warmup_len = 3 * self.period - 2;
+
+            if self.warmup.len() < warmup_len {
+                return None;
+            }
+
             // Step 1: Compute the first EMA1 as SMA of the first `period` values
-            let sum: f64 = self.data[..self.period].iter().sum();
+            let sum: f64 = self.warmup[..self.period].iter().sum();
             self.prev_ema1 = sum / self.period as f64;
 
             // Step 2: Collect `period` EMA1 values
             let mut ema1_values = Vec::with_capacity(self.period);
             ema1_values.push(self.prev_ema1);
 
-            for data in self.data.iter().take(3 * self.period - 2).skip(self.period) {
-                let ema1 = (data - self.prev_ema1) * self.smoothing + self.prev_ema1;
-                self.prev_ema1 = ema1;
-                ema1_values.push(ema1);
+            for &v in &self.warmup[self.period..warmup_len] {
+                self.prev_ema1 = (v - self.prev_ema1) * self.smoothing + self.prev_ema1;
+                ema1_values.push(self.prev_ema1);
             }
 
             // Step 3: Compute the first EMA2 as SMA of `period` EMA1 values
-            let sum_ema1: f64 = ema1_values.iter().take(self.period).sum::<f64>();
+            let sum_ema1: f64 = ema1_values.iter().take(self.period).sum();
             self.prev_ema2 = sum_ema1 / self.period as f64;
 
             // Step 4: Collect `period` EMA2 values
             let mut ema2_values = Vec::with_capacity(self.period);
             ema2_values.push(self.prev_ema2);
 
-            for ema1 in ema1_values.iter().take(2 * self.period - 1).skip(self.period) {
-                let ema2 = (ema1 - self.prev_ema2) * self.smoothing + self.prev_ema2;
-                self.prev_ema2 = ema2;
-                ema2_values.push(ema2);
+            for &ema1 in &ema1_values[self.period..] {
+                self.prev_ema2 = (ema1 - self.prev_ema2) * self.smoothing + self.prev_ema2;
+                ema2_values.push(self.prev_ema2);
             }
 
             // Step 5: Compute the first EMA3 as SMA of `period` EMA2 values
             let sum_ema2: f64 = ema2_values.iter().sum();
             self.prev_ema3 = sum_ema2 / self.period as f64;
 
-            // Step 6: Compute the first TEMA value
-            let tema = (3.0 * self.prev_ema1) - (3.0 * self.prev_ema2) + self.prev_ema3;
-            self.index += 1;
-            return Some(tema);
-        }
-
-        // Offset index to start after initialization phase
-        let price_index = self.index + (3 * self.period - 3);
-        let price = self.data[price_index];
+            self.seeded = true;
+            self.warmup = Vec::new();
 
-        // Compute EMA1
-        self.prev_ema1 = (price - self.prev_ema1) * self.smoothing + self.prev_ema1;
+            return Some((3.0 * self.prev_ema1) - (3.0 * self.prev_ema2) + self.prev_ema3);
+        }
 
-        // Compute EMA2
+        self.prev_ema1 = (value - self.prev_ema1) * self.smoothing + self.prev_ema1;
         self.prev_ema2 = (self.prev_ema1 - self.prev_ema2) * self.smoothing + self.prev_ema2;
-
-        // Compute EMA3
         self.prev_ema3 = (self.prev_ema2 - self.prev_ema3) * self.smoothing + self.prev_ema3;
 
-        // Compute TEMA
-        let tema = (3.0 * self.prev_ema1) - (3.0 * self.prev_ema2) + self.prev_ema3;
+        Some((3.0 * self.prev_ema1) - (3.0 * self.prev_ema2) + self.prev_ema3)
+    }
 
-        self.index += 1;
-        Some(tema)
+    /// Clears the warm-up buffer and all three EMA accumulators, as if freshly constructed.
+    fn reset(&mut self) {
+        self.prev_ema1 = 0.0;
+        self.prev_ema2 = 0.0;
+        self.prev_ema3 = 0.0;
+        self.warmup = Vec::with_capacity(3 * self.period - 2);
+        self.seeded = false;
     }
+}
+
+impl Iterator for Tema<'_> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.data.len() {
+            let value = self.data[self.index];
+            self.index += 1;
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.len.saturating_sub(self.index + (3 * self.period - 3)) + 1;
-        (remaining, Some(remaining))
+            if let Some(result) = self.update(value) {
+                return Some(result);
+            }
+        }
+
+        None
     }
 }
 
@@ -138,10 +180,7 @@ impl<'a> Indicator<'a> for Tema<'a> {
     ///
     /// Returns a vector containing the TEMA values over the dataset.
     fn calculate(&mut self) -> Result<Self::Output, String> {
-        let mut result = Vec::with_capacity(self.len);
-        result.extend(self);
-
-        Ok(result)
+        Ok(self.collect())
     }
 }
 
@@ -183,4 +222,25 @@ mod tests {
             assert!((r - e).abs() < 1e-6, "Expected {}, got {}", e, r);
         }
     }
+
+    #[test]
+    fn test_tema_streaming_matches_slice_based() {
+        let data = [
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+            8.194272150313072, 9.563328995789382, 0.7634815269862714, 12.914846107673528,
+            11.155265802245399, 3.217940616681935, 2.827359580250888, 2.8475777261239528,
+        ];
+        let period = 5;
+
+        let expected = Tema::new(&data, period).unwrap().calculate().unwrap();
+
+        let mut streaming = Tema::new_streaming(period).unwrap();
+        let streamed: Vec<f64> = data.iter().filter_map(|&value| streaming.update(value)).collect();
+
+        assert_eq!(streamed.len(), expected.len());
+        for (r, e) in streamed.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-6, "Expected {}, got {}", e, r);
+        }
+    }
 }
@@ -0,0 +1,168 @@
+//! # Triangular Moving Average (TRIMA) Indicator
+//!
+//! The **Triangular Moving Average (TRIMA)** is a double-smoothed
+//! [`crate::indicators::sma::Sma`]: smoothing the data once and then smoothing that result
+//! again produces a triangular weighting, where the middle of the lookback window carries
+//! the most weight and the edges carry the least.
+//!
+//! ## Formula
+//! ```text
+//! w     = ceil((period + 1) / 2)
+//! TRIMA = SMA(SMA(price, w), w)
+//! ```
+//!
+//! ## Performance Considerations
+//! - Computes both SMA passes eagerly at construction time, the same "eager computation"
+//!   approach used by [`crate::indicators::t3::T3`] for multi-stage moving averages.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::indicators::trima::Trima;
+//!
+//! let price_data: Vec<f64> = (1..=20).map(|x| x as f64).collect();
+//! let period = 5;
+//!
+//! let mut trima = Trima::new(&price_data, period).unwrap();
+//! let trima_values = trima.calculate().unwrap();
+//!
+//! println!("TRIMA Values: {:?}", trima_values);
+//! ```
+//!
+//! ## Struct Definition
+
+use crate::indicators::sma::Sma;
+use crate::Indicator;
+
+/// **The Triangular Moving Average (TRIMA) Indicator**
+///
+/// TRIMA smooths an already-smoothed SMA a second time, producing a triangular weighting
+/// across the lookback window that favors the middle of the window over its edges.
+#[derive(Clone, Debug)]
+pub struct Trima {
+    /// The precomputed TRIMA values.
+    values: Vec<f64>,
+    /// Current index in the iteration process.
+    index: usize,
+}
+
+impl Trima {
+    /// Creates a new instance of the Triangular Moving Average (TRIMA).
+    ///
+    /// # Arguments
+    /// - `data`: A reference to the input price data.
+    /// - `period`: The lookback period. Internally both SMA passes use a window of
+    ///   `ceil((period + 1) / 2)`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `period` is zero.
+    /// - The `data` is too short to produce at least one TRIMA value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::indicators::trima::Trima;
+    ///
+    /// let price_data: Vec<f64> = (1..=20).map(|x| x as f64).collect();
+    /// let trima = Trima::new(&price_data, 5);
+    ///
+    /// assert!(trima.is_ok());
+    /// ```
+    pub fn new(data: &[f64], period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0.".to_string());
+        }
+
+        let w = (period + 2) / 2;
+        let warmup_len = 2 * w - 1;
+        if data.len() < warmup_len {
+            return Err("Period cannot be greater than input data length.".to_string());
+        }
+
+        let sma1 = Sma::new(data, w)?.calculate()?;
+        let values = Sma::new(&sma1, w)?.calculate()?;
+
+        Ok(Self { values, index: 0 })
+    }
+}
+
+impl Iterator for Trima {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.values.get(self.index).copied()?;
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.values.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> Indicator<'a> for Trima {
+    type Output = Vec<f64>;
+
+    /// Computes the Triangular Moving Average (TRIMA) for the given data.
+    ///
+    /// Returns a vector containing the TRIMA values over the dataset.
+    fn calculate(&mut self) -> Result<Self::Output, String> {
+        Ok(self.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trima_matches_manual_double_sma() {
+        let data: Vec<f64> = (1..=20).map(|x| (x as f64 * 0.4).sin() * 5.0 + x as f64).collect();
+        let period = 5;
+
+        let mut trima = Trima::new(&data, period).unwrap();
+        let result = trima.calculate().unwrap();
+
+        let w = (period + 2) / 2;
+        let sma1 = Sma::new(&data, w).unwrap().calculate().unwrap();
+        let expected = Sma::new(&sma1, w).unwrap().calculate().unwrap();
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
+
+    #[test]
+    fn test_trima_even_and_odd_periods_use_same_half_window() {
+        let data: Vec<f64> = (1..=20).map(|x| x as f64).collect();
+
+        // period = 5 (odd) and period = 4 (even) both map to w = 3.
+        assert_eq!((5usize + 2) / 2, 3);
+        assert_eq!((4usize + 2) / 2, 3);
+
+        assert!(Trima::new(&data, 5).is_ok());
+        assert!(Trima::new(&data, 4).is_ok());
+    }
+
+    #[test]
+    fn test_trima_invalid_period() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            Trima::new(&data, 0).is_err(),
+            "TRIMA should return an error for a zero period."
+        );
+    }
+
+    #[test]
+    fn test_trima_short_data() {
+        let data = vec![1.0, 2.0, 3.0];
+
+        assert!(
+            Trima::new(&data, 10).is_err(),
+            "TRIMA should return an error when data is too short."
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! # Volume Functions
+//!
+//! Free functions that fold traded volume into the price transforms in
+//! [`crate::indicators::price`]: a cumulative Volume Weighted Average Price, and the Money
+//! Flow Index, a volume-weighted analogue of [`crate::indicators::rsi::Rsi`].
+
+use crate::indicators::price::typical_price;
+
+/// Computes the cumulative Volume Weighted Average Price:
+/// `sum(typical_price * volume) / sum(volume)`, accumulated from the first bar.
+///
+/// Unlike [`mfi`], VWAP has no lookback period and no warm-up: every output vector is the
+/// same length as the input slices, one value per bar.
+///
+/// # Errors
+/// Returns an error if `high`, `low`, `close`, and `volume` are empty or differ in length.
+///
+/// # Example
+/// ```rust
+/// use tarq::indicators::volume::vwap;
+///
+/// let high = vec![12.0, 13.0];
+/// let low = vec![8.0, 9.0];
+/// let close = vec![11.0, 12.0];
+/// let volume = vec![100.0, 200.0];
+///
+/// let values = vwap(&high, &low, &close, &volume).unwrap();
+/// assert_eq!(values.len(), 2);
+/// ```
+pub fn vwap(high: &[f64], low: &[f64], close: &[f64], volume: &[f64]) -> Result<Vec<f64>, String> {
+    let typical = typical_price(high, low, close)?;
+    if volume.len() != typical.len() {
+        return Err("Input slices must all be the same length.".to_string());
+    }
+
+    let mut cumulative_pv = 0.0;
+    let mut cumulative_volume = 0.0;
+
+    Ok(typical
+        .iter()
+        .zip(volume.iter())
+        .map(|(&price, &vol)| {
+            cumulative_pv += price * vol;
+            cumulative_volume += vol;
+
+            if cumulative_volume == 0.0 {
+                0.0
+            } else {
+                cumulative_pv / cumulative_volume
+            }
+        })
+        .collect())
+}
+
+/// Computes the Money Flow Index: a volume-weighted analogue of [`crate::indicators::rsi::Rsi`].
+///
+/// For each consecutive pair of bars, the typical price's raw money flow (`typical_price *
+/// volume`) is classified as positive or negative depending on whether the typical price rose
+/// or fell. Each output is then:
+/// ```text
+/// money_ratio = sum(positive_flow over period) / sum(negative_flow over period)
+/// MFI = 100 - 100 / (1 + money_ratio)
+/// ```
+/// `MFI` is `100` whenever the negative flow sum is `0`.
+///
+/// # Errors
+/// Returns an error if:
+/// - `period` is zero.
+/// - `high`, `low`, `close`, and `volume` differ in length.
+/// - The data does not contain at least `period + 1` bars.
+///
+/// # Example
+/// ```rust
+/// use tarq::indicators::volume::mfi;
+///
+/// let high = vec![12.0, 13.0, 12.5, 13.5, 14.0, 13.8];
+/// let low = vec![10.0, 11.0, 10.5, 11.5, 12.0, 11.8];
+/// let close = vec![11.0, 12.5, 11.0, 13.0, 13.5, 13.2];
+/// let volume = vec![100.0, 120.0, 90.0, 130.0, 110.0, 95.0];
+///
+/// let values = mfi(&high, &low, &close, &volume, 3).unwrap();
+/// assert_eq!(values.len(), 3);
+/// ```
+pub fn mfi(high: &[f64], low: &[f64], close: &[f64], volume: &[f64], period: usize) -> Result<Vec<f64>, String> {
+    if period == 0 {
+        return Err("Period must be greater than 0".to_string());
+    }
+
+    let typical = typical_price(high, low, close)?;
+    if volume.len() != typical.len() {
+        return Err("Input slices must all be the same length.".to_string());
+    }
+    if typical.len() < period + 1 {
+        return Err("Period cannot be greater than input data length".to_string());
+    }
+
+    let raw_flow: Vec<f64> = typical.iter().zip(volume.iter()).map(|(&price, &vol)| price * vol).collect();
+
+    let (positive, negative): (Vec<f64>, Vec<f64>) = typical
+        .windows(2)
+        .zip(raw_flow[1..].iter())
+        .map(|(window, &flow)| {
+            if window[1] > window[0] {
+                (flow, 0.0)
+            } else if window[1] < window[0] {
+                (0.0, flow)
+            } else {
+                (0.0, 0.0)
+            }
+        })
+        .unzip();
+
+    let mut positive_sum: f64 = positive[..period].iter().sum();
+    let mut negative_sum: f64 = negative[..period].iter().sum();
+
+    let mut result = Vec::with_capacity(positive.len() - period + 1);
+    result.push(mfi_from(positive_sum, negative_sum));
+
+    for i in period..positive.len() {
+        positive_sum += positive[i] - positive[i - period];
+        negative_sum += negative[i] - negative[i - period];
+        result.push(mfi_from(positive_sum, negative_sum));
+    }
+
+    Ok(result)
+}
+
+fn mfi_from(positive_sum: f64, negative_sum: f64) -> f64 {
+    if negative_sum == 0.0 {
+        100.0
+    } else {
+        100.0 - 100.0 / (1.0 + positive_sum / negative_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vwap_matches_manual_cumulative_average() {
+        let high = vec![12.0, 13.0, 14.0];
+        let low = vec![8.0, 9.0, 10.0];
+        let close = vec![11.0, 12.0, 13.0];
+        let volume = vec![100.0, 200.0, 50.0];
+
+        let result = vwap(&high, &low, &close, &volume).unwrap();
+
+        let typical = typical_price(&high, &low, &close).unwrap();
+        let mut cum_pv = 0.0;
+        let mut cum_v = 0.0;
+        let expected: Vec<f64> = typical
+            .iter()
+            .zip(volume.iter())
+            .map(|(&p, &v)| {
+                cum_pv += p * v;
+                cum_v += v;
+                cum_pv / cum_v
+            })
+            .collect();
+
+        assert_eq!(result.len(), expected.len());
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_vwap_rejects_mismatched_lengths() {
+        let high = vec![12.0, 13.0];
+        let low = vec![8.0, 9.0];
+        let close = vec![11.0, 12.0];
+        let volume = vec![100.0];
+
+        assert!(vwap(&high, &low, &close, &volume).is_err());
+    }
+
+    #[test]
+    fn test_mfi_all_rising_is_100() {
+        let high: Vec<f64> = (1..=10).map(|x| x as f64 + 1.0).collect();
+        let low: Vec<f64> = (1..=10).map(|x| x as f64 - 1.0).collect();
+        let close: Vec<f64> = (1..=10).map(|x| x as f64).collect();
+        let volume = vec![100.0; 10];
+
+        let result = mfi(&high, &low, &close, &volume, 3).unwrap();
+
+        assert!(result.iter().all(|&v| (v - 100.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_mfi_invalid_period() {
+        let high = vec![12.0, 13.0, 14.0];
+        let low = vec![8.0, 9.0, 10.0];
+        let close = vec![11.0, 12.0, 13.0];
+        let volume = vec![100.0, 200.0, 50.0];
+
+        assert!(mfi(&high, &low, &close, &volume, 0).is_err());
+        assert!(mfi(&high, &low, &close, &volume, 5).is_err());
+    }
+}
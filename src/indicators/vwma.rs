@@ -44,7 +44,8 @@
 //!
 //! ## Struct Definition
 
-use crate::Indicator;
+use crate::circular_buffer::CircularBuffer;
+use crate::{Indicator, StreamingPriceVolume};
 
 /// **The Volume Weighted Moving Average (VWMA) Indicator**
 ///
@@ -65,6 +66,9 @@ pub struct Vwma<'a> {
     rolling_sum: f64,
     /// Rolling sum of volume values.
     rolling_sum_vol: f64,
+    /// Sliding window of the last `period` (price, volume) pairs backing
+    /// [`StreamingPriceVolume::update`]'s O(1) rolling sums.
+    window: CircularBuffer<(f64, f64)>,
 }
 
 impl<'a> Vwma<'a> {
@@ -110,8 +114,69 @@ impl<'a> Vwma<'a> {
             index: 0,
             rolling_sum: 0.0,
             rolling_sum_vol: 0.0,
+            window: CircularBuffer::new(period),
         })
     }
+
+    /// Creates a streaming-only instance of the VWMA with no backing slices.
+    ///
+    /// Use this constructor when (price, volume) pairs arrive one at a time. Feed
+    /// samples through [`StreamingPriceVolume::update`]; [`Indicator::calculate`] will
+    /// report an empty result since there are no slices to replay.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    pub fn new_streaming(period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be set to a number greater than 0".to_string());
+        }
+
+        Ok(Self {
+            data: &[],
+            volume: &[],
+            period,
+            index: 0,
+            rolling_sum: 0.0,
+            rolling_sum_vol: 0.0,
+            window: CircularBuffer::new(period),
+        })
+    }
+}
+
+impl StreamingPriceVolume for Vwma<'_> {
+    /// Advances the VWMA by exactly one (price, volume) sample.
+    ///
+    /// Backs the rolling sums with a [`CircularBuffer`] holding the last `period`
+    /// (price, volume) pairs: on every push the oldest pair drops out of both sums and
+    /// the newest one is added in, keeping the update O(1) regardless of `period`.
+    /// Returns `None` until `period` samples have been pushed.
+    fn update(&mut self, price: f64, volume: f64) -> Option<f64> {
+        if !self.window.is_full() {
+            self.window.push((price, volume));
+            self.rolling_sum += price * volume;
+            self.rolling_sum_vol += volume;
+
+            if !self.window.is_full() {
+                return None;
+            }
+
+            return Some(self.rolling_sum / self.rolling_sum_vol);
+        }
+
+        let (old_price, old_volume) = *self.window.front().unwrap();
+        self.window.push((price, volume));
+        self.rolling_sum += price * volume - old_price * old_volume;
+        self.rolling_sum_vol += volume - old_volume;
+
+        Some(self.rolling_sum / self.rolling_sum_vol)
+    }
+
+    /// Clears the rolling (price, volume) window and sums, as if freshly constructed.
+    fn reset(&mut self) {
+        self.window.clear();
+        self.rolling_sum = 0.0;
+        self.rolling_sum_vol = 0.0;
+    }
 }
 
 impl Iterator for Vwma<'_> {
@@ -246,5 +311,26 @@ mod tests {
             "VWMA should return an error when data is shorter than the period."
         );
     }
+
+    #[test]
+    fn test_vwma_streaming_matches_slice_based() {
+        let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        let volume_data = vec![100.0, 200.0, 150.0, 250.0, 300.0, 350.0, 400.0];
+        let period = 3;
+
+        let expected = Vwma::new(&price_data, &volume_data, period).unwrap().calculate().unwrap();
+
+        let mut streaming = Vwma::new_streaming(period).unwrap();
+        let streamed: Vec<f64> = price_data
+            .iter()
+            .zip(volume_data.iter())
+            .filter_map(|(&price, &volume)| streaming.update(price, volume))
+            .collect();
+
+        assert_eq!(streamed.len(), expected.len());
+        for (r, e) in streamed.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-9, "Expected {}, got {}", e, r);
+        }
+    }
 }
 
@@ -44,12 +44,13 @@
 //!
 //! ## Struct Definition
 
-use crate::Indicator;
+use crate::circular_buffer::CircularBuffer;
+use crate::{Indicator, Streaming};
 
 /// **The Weighted Moving Average (WMA) Indicator**
 ///
-/// WMA is a moving average that gives more weight to recent prices, making it more 
-/// responsive to short-term price movements. It assigns weights linearly, with 
+/// WMA is a moving average that gives more weight to recent prices, making it more
+/// responsive to short-term price movements. It assigns weights linearly, with
 /// the most recent price having the highest weight.
 #[derive(Clone, Debug)]
 pub struct Wma<'a> {
@@ -65,6 +66,8 @@ pub struct Wma<'a> {
     period_sub: f64,
     /// Precomputed sum of weights for normalization.
     weight_total: f64,
+    /// Sliding window of the last `period` values, used by the streaming variant.
+    window: CircularBuffer<f64>,
 }
 
 impl<'a> Wma<'a> {
@@ -106,10 +109,74 @@ impl<'a> Wma<'a> {
             period_sum: 0.0, // Will be computed when next() is first called
             period_sub: 0.0,
             weight_total,
+            window: CircularBuffer::new(period),
+        })
+    }
+
+    /// Creates a streaming-only instance of the WMA with no backing slice.
+    ///
+    /// Use this constructor when prices arrive one at a time. Feed samples through
+    /// [`Streaming::update`]; [`Indicator::calculate`] will report an empty result since
+    /// there is no slice to replay.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is zero.
+    pub fn new_streaming(period: usize) -> Result<Self, String> {
+        if period == 0 {
+            return Err("Period must be greater than 0.".to_string());
+        }
+
+        let weight_total = (period * (period + 1) / 2) as f64;
+
+        Ok(Self {
+            data: &[],
+            period,
+            index: 0,
+            period_sum: 0.0,
+            period_sub: 0.0,
+            weight_total,
+            window: CircularBuffer::new(period),
         })
     }
 }
 
+impl Streaming for Wma<'_> {
+    /// Advances the WMA by exactly one sample.
+    ///
+    /// Returns `None` until `period` samples have been pushed, after which every call
+    /// slides the window forward and returns `Some`.
+    fn update(&mut self, value: f64) -> Option<f64> {
+        if !self.window.is_full() {
+            self.window.push(value);
+
+            if !self.window.is_full() {
+                return None;
+            }
+
+            self.period_sum = self.window.iter().enumerate().map(|(i, &price)| price * (i + 1) as f64).sum();
+            self.period_sub = self.window.iter().sum();
+
+            return Some(self.period_sum / self.weight_total);
+        }
+
+        let outgoing = *self.window.front().unwrap();
+        self.window.push(value);
+
+        self.period_sum += value * self.period as f64 - self.period_sub;
+        self.period_sub += value - outgoing;
+
+        Some(self.period_sum / self.weight_total)
+    }
+
+    /// Clears the rolling window and sums back to the pre-warmup state.
+    fn reset(&mut self) {
+        self.window.clear();
+        self.period_sum = 0.0;
+        self.period_sub = 0.0;
+        self.index = 0;
+    }
+}
+
 impl Iterator for Wma<'_> {
     type Item = f64;
 
@@ -215,4 +282,24 @@ mod tests {
             assert!((actual - exp).abs() < 1e-6, "Value at index {} differs: expected {}, got {}", i, exp, actual);
         }
     }
+
+    #[test]
+    fn test_wma_streaming_matches_slice_based() {
+        let data = vec![
+            5.29411352124624, 12.669143122046927, 9.869522455185985, 8.162828597722068,
+            2.4970385976631873, 2.496729860303394, 1.243470235752953, 11.58705466591917,
+            8.194272150313072, 9.563328995789382, 0.7634815269862714, 12.914846107673528,
+        ];
+        let period = 5;
+
+        let expected = Wma::new(&data, period).unwrap().calculate().unwrap();
+
+        let mut streaming = Wma::new_streaming(period).unwrap();
+        let streamed: Vec<f64> = data.iter().filter_map(|&value| streaming.update(value)).collect();
+
+        assert_eq!(streamed.len(), expected.len());
+        for (r, e) in streamed.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-6, "Expected {}, got {}", e, r);
+        }
+    }
 }
\ No newline at end of file
@@ -65,6 +65,9 @@
 
 pub mod indicators;
 pub mod enums;
+pub mod series;
+pub mod candle;
+pub(crate) mod circular_buffer;
 mod python_api;
 
 pub trait Indicator<'a>: Iterator {
@@ -74,4 +77,74 @@ pub trait Indicator<'a>: Iterator {
 
 }
 
+/// A companion trait for indicators that can be advanced one sample at a time.
+///
+/// Unlike [`Indicator::calculate`], which requires the full input slice up front,
+/// `update` feeds a single new value into the indicator's internal state and
+/// returns the latest computed value once enough samples have arrived to leave
+/// the warm-up period. While warming up it returns `None`.
+pub trait Streaming {
+    /// Advances the indicator by exactly one sample.
+    ///
+    /// Returns `None` until enough samples have been pushed to satisfy the
+    /// indicator's warm-up requirement, after which it returns `Some` on every call.
+    fn update(&mut self, value: f64) -> Option<f64>;
+
+    /// Clears the indicator's internal state, returning it to the same warm-up
+    /// state as a freshly constructed streaming instance.
+    ///
+    /// Lets a long-lived indicator be reused across a new feed (e.g. after a gap in
+    /// the data) without reallocating.
+    fn reset(&mut self);
+}
+
+/// A companion trait for indicators driven by OHLC bars rather than a single price.
+///
+/// Mirrors [`Streaming`]'s push-based model for [`crate::indicators::atr::Atr`], which needs
+/// the high, low, and close of each bar (plus the previous close) to compute a new sample.
+pub trait StreamingOhlc {
+    /// Advances the indicator by exactly one OHLC bar.
+    ///
+    /// Returns `None` until enough bars have been pushed to satisfy the indicator's
+    /// warm-up requirement, after which it returns `Some` on every call.
+    fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64>;
+
+    /// Clears the indicator's internal state, returning it to the same warm-up
+    /// state as a freshly constructed streaming instance.
+    fn reset(&mut self);
+}
+
+/// A companion trait for indicators that emit an (upper, middle, lower) band triple per sample
+/// rather than a single value.
+///
+/// Mirrors [`Streaming`]'s push-based model for [`crate::indicators::bbands::BBands`], whose
+/// output is a band triple rather than a single `f64`.
+pub trait StreamingBands {
+    /// Advances the indicator by exactly one sample.
+    ///
+    /// Returns `None` until enough samples have been pushed to satisfy the indicator's
+    /// warm-up requirement, after which it returns `Some((upper, middle, lower))` on every call.
+    fn update(&mut self, value: f64) -> Option<(f64, f64, f64)>;
+
+    /// Clears the indicator's internal state, returning it to the same warm-up
+    /// state as a freshly constructed streaming instance.
+    fn reset(&mut self);
+}
+
+/// A companion trait for indicators driven by (price, volume) pairs rather than a single price.
+///
+/// Mirrors [`Streaming`]'s push-based model for [`crate::indicators::vwma::Vwma`], which needs
+/// both the price and the volume of each new bar to update its rolling sums.
+pub trait StreamingPriceVolume {
+    /// Advances the indicator by exactly one (price, volume) sample.
+    ///
+    /// Returns `None` until enough samples have been pushed to satisfy the indicator's
+    /// warm-up requirement, after which it returns `Some` on every call.
+    fn update(&mut self, price: f64, volume: f64) -> Option<f64>;
+
+    /// Clears the indicator's internal state, returning it to the same warm-up
+    /// state as a freshly constructed streaming instance.
+    fn reset(&mut self);
+}
+
 
@@ -2,6 +2,7 @@ use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyString;
 use numpy::{PyArray1, PyReadonlyArray1};
+use crate::series::Series;
 use crate::indicators::{
     bbands::BBands,
     sma::Sma,
@@ -13,7 +14,15 @@ use crate::indicators::{
     wma::Wma,
     dema::Dema,
     tema::Tema,
-    kama::Kama
+    t3::T3,
+    hma::Hma,
+    alma::Alma,
+    trima::Trima,
+    kama::Kama,
+    linreg::LinReg,
+    momentum,
+    price,
+    volume,
 };
 use crate::Indicator;
 
@@ -29,15 +38,34 @@ fn tarq(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(atr, m)?)?;
     m.add_function(wrap_pyfunction!(dema, m)?)?;
     m.add_function(wrap_pyfunction!(tema, m)?)?;
+    m.add_function(wrap_pyfunction!(t3, m)?)?;
+    m.add_function(wrap_pyfunction!(hma, m)?)?;
+    m.add_function(wrap_pyfunction!(alma, m)?)?;
+    m.add_function(wrap_pyfunction!(trima, m)?)?;
     m.add_function(wrap_pyfunction!(kama, m)?)?;
     m.add_function(wrap_pyfunction!(bbands, m)?)?;
     m.add_function(wrap_pyfunction!(bbpb, m)?)?;
     m.add_function(wrap_pyfunction!(stddev, m)?)?;
+    m.add_function(wrap_pyfunction!(median_price, m)?)?;
+    m.add_function(wrap_pyfunction!(typical_price, m)?)?;
+    m.add_function(wrap_pyfunction!(weighted_close, m)?)?;
+    m.add_function(wrap_pyfunction!(macd, m)?)?;
+    m.add_function(wrap_pyfunction!(rsi, m)?)?;
+    m.add_function(wrap_pyfunction!(aosc, m)?)?;
+    m.add_function(wrap_pyfunction!(vwap, m)?)?;
+    m.add_function(wrap_pyfunction!(mfi, m)?)?;
+    m.add_function(wrap_pyfunction!(linreg, m)?)?;
+    m.add_function(wrap_pyfunction!(linreg_slope, m)?)?;
+    m.add_function(wrap_pyfunction!(linreg_intercept, m)?)?;
+    m.add_function(wrap_pyfunction!(tsf, m)?)?;
     Ok(())
 }
 
-fn prepend_vec_in_place(data: &mut Vec<f64>, prepend_count: usize, value: f64) {
-    data.splice(0..0, std::iter::repeat(value).take(prepend_count));
+/// Re-aligns an indicator's (possibly warm-up-trimmed) output against the original input
+/// length by routing it through [`Series::from_warmup`], which left-pads with `None` rather
+/// than assuming `result` is no longer than `input_len`.
+fn align_to_input(result: Vec<f64>, input_len: usize) -> Vec<f64> {
+    Series::from_warmup(result, input_len).into_nan_vec()
 }
 
 
@@ -48,10 +76,8 @@ fn sma<'py>(py: Python<'py>, data: PyReadonlyArray1<'py, f64>, period: usize) ->
     let data_slice = data.as_slice()?;
     let mut sma = Sma::new(data_slice, period).map_err(|e| PyValueError::new_err(e.to_string()))?;
 
-    let mut result = sma.calculate().map_err(|e| PyValueError::new_err(e.to_string()))?;
-
-    let prepend_count = data_slice.len().saturating_sub(result.len());
-    prepend_vec_in_place(&mut result, prepend_count, f64::NAN);
+    let result = sma.calculate().map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let result = align_to_input(result, data_slice.len());
 
     Ok(PyArray1::from_vec(py, result))
 }
@@ -62,10 +88,8 @@ fn sma<'py>(py: Python<'py>, data: PyReadonlyArray1<'py, f64>, period: usize) ->
 fn ema<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
     let data_slice = data.as_slice()?;
     let mut ema = Ema::new(data_slice, period).unwrap();
-    let mut result = ema.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
-
-    let prepend_count = data_slice.len() - result.len();
-    prepend_vec_in_place(&mut result, prepend_count, f64::NAN);
+    let result = ema.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
 
     Ok(PyArray1::from_vec(py, result))
 }
@@ -75,10 +99,8 @@ fn ema<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyRe
 fn wma<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
     let data_slice = data.as_slice()?;
     let mut wma = Wma::new(data_slice, period).unwrap();
-    let mut result = wma.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
-
-    let prepend_count = data_slice.len() - result.len();
-    prepend_vec_in_place(&mut result, prepend_count, f64::NAN);
+    let result = wma.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
 
     Ok(PyArray1::from_vec(py, result))
 }
@@ -87,10 +109,8 @@ fn wma<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyRe
 fn dema<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
     let data_slice = data.as_slice()?;
     let mut dema = Dema::new(data_slice, period).unwrap();
-    let mut result = dema.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
-
-    let prepend_count = data_slice.len() - result.len();
-    prepend_vec_in_place(&mut result, prepend_count, f64::NAN);
+    let result = dema.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
 
     Ok(PyArray1::from_vec(py, result))
 }
@@ -99,10 +119,50 @@ fn dema<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyR
 fn tema<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
     let data_slice = data.as_slice()?;
     let mut tema = Tema::new(data_slice, period).unwrap();
-    let mut result = tema.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = tema.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+#[pyfunction]
+#[pyo3(signature = (data, period, v = 0.7))]
+fn t3<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize, v: f64) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let data_slice = data.as_slice()?;
+    let mut t3 = T3::new(data_slice, period, v).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let result = t3.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+#[pyfunction]
+fn hma<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let data_slice = data.as_slice()?;
+    let mut hma = Hma::new(data_slice, period).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let result = hma.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
+
+    Ok(PyArray1::from_vec(py, result))
+}
 
-    let prepend_count = data_slice.len() - result.len();
-    prepend_vec_in_place(&mut result, prepend_count, f64::NAN);
+#[pyfunction]
+#[pyo3(signature = (data, period, offset = 0.85, sigma = 6.0))]
+fn alma<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize, offset: f64, sigma: f64) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let data_slice = data.as_slice()?;
+    let mut alma = Alma::new(data_slice, period, offset, sigma).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let result = alma.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+#[pyfunction]
+fn trima<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let data_slice = data.as_slice()?;
+    let mut trima = Trima::new(data_slice, period).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let result = trima.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
 
     Ok(PyArray1::from_vec(py, result))
 }
@@ -112,10 +172,8 @@ fn tema<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyR
 fn kama<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize, fast: usize, slow: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
     let data_slice = data.as_slice()?;
     let mut kama = Kama::new(data_slice, period, fast, slow).unwrap();
-    let mut result = kama.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
-
-    let prepend_count = data_slice.len() - result.len();
-    prepend_vec_in_place(&mut result, prepend_count, f64::NAN);
+    let result = kama.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
 
     Ok(PyArray1::from_vec(py, result))
 }
@@ -127,10 +185,8 @@ fn kama<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize, fast:
 fn stddev<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize, ddof: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
     let data_slice = data.as_slice()?;
     let mut stddev = StdDev::new(data_slice, period, ddof).unwrap();
-    let mut result = stddev.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
-
-    let prepend_count = data_slice.len() - result.len();
-    prepend_vec_in_place(&mut result, prepend_count, f64::NAN);
+    let result = stddev.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
 
     Ok(PyArray1::from_vec(py, result))
 }
@@ -146,10 +202,8 @@ fn vwma<'py>(
     let data_slice = data.as_slice()?;
     let volume_slice = volume.as_slice()?;
     let mut vwma = Vwma::new(data_slice, volume_slice, period).unwrap();
-    let mut result = vwma.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
-
-    let prepend_count = data_slice.len() - result.len();
-    prepend_vec_in_place(&mut result, prepend_count, f64::NAN);
+    let result = vwma.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
 
     Ok(PyArray1::from_vec(py, result))
 }
@@ -166,10 +220,8 @@ fn atr<'py>(
     let low_slice = low.as_slice()?;
     let close_slice = close.as_slice()?;
     let mut atr = Atr::new(high_slice, low_slice, close_slice, period.unwrap_or(14)).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
-    let mut result = atr.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
-
-    let prepend_count = high_slice.len() - result.len();
-    prepend_vec_in_place(&mut result, prepend_count, f64::NAN);
+    let result = atr.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, high_slice.len());
 
     Ok(PyArray1::from_vec(py, result))
 }
@@ -206,12 +258,11 @@ fn bbands<'py>(
     };
 
     let mut bb = BBands::new(data_slice, period, std_dev, ma_type_enum).unwrap();
-    let (mut upper, mut middle, mut lower) = bb.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let (upper, middle, lower) = bb.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
 
-    let prepend_count = data_slice.len() - upper.len();
-    prepend_vec_in_place(&mut upper, prepend_count, f64::NAN);
-    prepend_vec_in_place(&mut middle, prepend_count, f64::NAN);
-    prepend_vec_in_place(&mut lower, prepend_count, f64::NAN);
+    let upper = align_to_input(upper, data_slice.len());
+    let middle = align_to_input(middle, data_slice.len());
+    let lower = align_to_input(lower, data_slice.len());
 
     let upper_py = PyArray1::from_vec(py, upper);
     let middle_py = PyArray1::from_vec(py, middle);
@@ -258,11 +309,183 @@ fn bbpb<'py>(
     };
 
     let mut bbpb = Bbpb::new(data_slice, period, std_dev, ma_type_enum).unwrap();
-    let mut result = bbpb.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
-    
-    let prepend_count = data_slice.len() - result.len();
-    prepend_vec_in_place(&mut result, prepend_count, f64::NAN);
-    
+    let result = bbpb.calculate().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
+
     Ok(PyArray1::from_vec(py, result))
 }
 
+/// Median price: `(H + L) / 2`. No warm-up, so the output matches the input length directly.
+#[pyfunction]
+fn median_price<'py>(py: Python<'py>, high: PyReadonlyArray1<f64>, low: PyReadonlyArray1<f64>) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let result = price::median_price(high.as_slice()?, low.as_slice()?).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+/// Typical price: `(H + L + C) / 3`. No warm-up, so the output matches the input length directly.
+#[pyfunction]
+fn typical_price<'py>(
+    py: Python<'py>,
+    high: PyReadonlyArray1<f64>,
+    low: PyReadonlyArray1<f64>,
+    close: PyReadonlyArray1<f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let result = price::typical_price(high.as_slice()?, low.as_slice()?, close.as_slice()?)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+/// Weighted close: `(H + L + 2C) / 4`. No warm-up, so the output matches the input length directly.
+#[pyfunction]
+fn weighted_close<'py>(
+    py: Python<'py>,
+    high: PyReadonlyArray1<f64>,
+    low: PyReadonlyArray1<f64>,
+    close: PyReadonlyArray1<f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let result = price::weighted_close(high.as_slice()?, low.as_slice()?, close.as_slice()?)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+/// MACD line, signal line, and histogram.
+#[pyfunction]
+#[pyo3(signature = (data, fast_period = 12, slow_period = 26, signal_period = 9))]
+fn macd<'py>(
+    py: Python<'py>,
+    data: PyReadonlyArray1<f64>,
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+) -> PyResult<Bound<'py, PyAny>> {
+    let data_slice = data.as_slice()?;
+    let (macd_line, signal_line, histogram) =
+        momentum::macd(data_slice, fast_period, slow_period, signal_period).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+
+    let macd_line = align_to_input(macd_line, data_slice.len());
+    let signal_line = align_to_input(signal_line, data_slice.len());
+    let histogram = align_to_input(histogram, data_slice.len());
+
+    let macd_py = PyArray1::from_vec(py, macd_line);
+    let signal_py = PyArray1::from_vec(py, signal_line);
+    let histogram_py = PyArray1::from_vec(py, histogram);
+
+    let collections = PyModule::import(py, "collections")?;
+    let namedtuple = collections.getattr("namedtuple")?;
+    let namedtuple_type = namedtuple.call1(("Macd", "macd signal histogram"))?;
+
+    let namedtuple_instance = namedtuple_type.call1((macd_py, signal_py, histogram_py))?;
+    Ok(namedtuple_instance)
+}
+
+/// Relative Strength Index.
+#[pyfunction]
+fn rsi<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let data_slice = data.as_slice()?;
+    let result = momentum::rsi(data_slice, period).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, data_slice.len());
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+/// Awesome Oscillator: the difference between a short and a long SMA of the median price.
+#[pyfunction]
+fn aosc<'py>(
+    py: Python<'py>,
+    high: PyReadonlyArray1<f64>,
+    low: PyReadonlyArray1<f64>,
+    short_period: usize,
+    long_period: usize,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let high_slice = high.as_slice()?;
+    let low_slice = low.as_slice()?;
+    let result = momentum::aosc(high_slice, low_slice, short_period, long_period).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, high_slice.len());
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+/// Cumulative Volume Weighted Average Price. No warm-up, so the output matches the input
+/// length directly.
+#[pyfunction]
+fn vwap<'py>(
+    py: Python<'py>,
+    high: PyReadonlyArray1<f64>,
+    low: PyReadonlyArray1<f64>,
+    close: PyReadonlyArray1<f64>,
+    volume: PyReadonlyArray1<f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let result = volume::vwap(high.as_slice()?, low.as_slice()?, close.as_slice()?, volume.as_slice()?)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+/// Money Flow Index: a volume-weighted analogue of RSI.
+#[pyfunction]
+fn mfi<'py>(
+    py: Python<'py>,
+    high: PyReadonlyArray1<f64>,
+    low: PyReadonlyArray1<f64>,
+    close: PyReadonlyArray1<f64>,
+    volume: PyReadonlyArray1<f64>,
+    period: usize,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let high_slice = high.as_slice()?;
+    let result = volume::mfi(high_slice, low.as_slice()?, close.as_slice()?, volume.as_slice()?, period)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(result, high_slice.len());
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+/// Linear regression value: the least-squares line fit over each rolling window, projected
+/// to the window's most recent bar. Also exposed as [`tsf`] (Time Series Forecast).
+#[pyfunction]
+fn linreg<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let data_slice = data.as_slice()?;
+    let (values, _, _) = LinReg::new(data_slice, period)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?
+        .calculate()
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(values, data_slice.len());
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+/// Linear regression slope over each rolling window.
+#[pyfunction]
+fn linreg_slope<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let data_slice = data.as_slice()?;
+    let (_, slopes, _) = LinReg::new(data_slice, period)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?
+        .calculate()
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(slopes, data_slice.len());
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+/// Linear regression intercept over each rolling window.
+#[pyfunction]
+fn linreg_intercept<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let data_slice = data.as_slice()?;
+    let (_, _, intercepts) = LinReg::new(data_slice, period)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?
+        .calculate()
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let result = align_to_input(intercepts, data_slice.len());
+
+    Ok(PyArray1::from_vec(py, result))
+}
+
+/// Time Series Forecast: an alias for [`linreg`], the regression line's value projected to
+/// the most recent bar of each rolling window.
+#[pyfunction]
+fn tsf<'py>(py: Python<'py>, data: PyReadonlyArray1<f64>, period: usize) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    linreg(py, data, period)
+}
+
@@ -0,0 +1,405 @@
+//! # Series
+//!
+//! Composite indicators (MACD, Bollinger %B, oscillator normalization, ...) often need to
+//! combine several indicator outputs that each start at a different warm-up offset. Lining
+//! those outputs up by hand (as [`crate::indicators::macd::Macd`] and
+//! [`crate::indicators::t3::T3`] currently do) means re-deriving the offset arithmetic every
+//! time. **`Series`** gives composite indicators a shared algebra for this: a nullable,
+//! position-aligned numeric series where missing (warm-up) samples are `None` and propagate
+//! through arithmetic the way `NaN` would, without `NaN`'s surprising comparison semantics.
+//!
+//! ## Alignment
+//! Every `Series` is indexed against the *original* input length, not its own warm-up-trimmed
+//! length: index `0` always corresponds to the first input sample. [`Series::from_warmup`]
+//! builds a `Series` from an indicator's (shorter) output plus the original data length,
+//! left-padding the warm-up gap with `None` so two indicators with different periods can be
+//! combined index-for-index without manual offset bookkeeping. [`Series::into_nan_vec`] reverses
+//! this at a language boundary (e.g. the Python bindings), filling `None` with `f64::NAN` in one
+//! call instead of re-deriving `data.len() - result.len()` at every call site.
+//!
+//! ## Operators
+//! `&Series op &Series` and `&Series op f64` are available via `std::ops` (`Add`, `Sub`, `Mul`,
+//! `Div`): the `&Series` forms are thin wrappers over the like-named methods, and the `f64`
+//! forms apply the scalar to every position. [`Series::rolling_sum`] gives a
+//! `None`-propagating trailing window sum.
+//!
+//! ## Example Usage
+//! ```rust
+//! use tarq::*;
+//! use tarq::series::Series;
+//! use tarq::indicators::ema::Ema;
+//! use tarq::indicators::sma::Sma;
+//!
+//! let price_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+//!
+//! let ema_values = Ema::new(&price_data, 3).unwrap().calculate().unwrap();
+//! let sma_values = Sma::new(&price_data, 5).unwrap().calculate().unwrap();
+//!
+//! let ema_series = Series::from_warmup(ema_values, price_data.len());
+//! let sma_series = Series::from_warmup(sma_values, price_data.len());
+//!
+//! let spread = ema_series.sub(&sma_series);
+//! println!("Spread: {:?}", spread.into_vec(0.0));
+//! ```
+//!
+//! ## Struct Definition
+
+/// **A nullable, position-aligned numeric series**
+///
+/// Wraps a `Vec<Option<f64>>`. `None` marks a position with no value yet (e.g. still
+/// warming up); every arithmetic operation propagates `None` the moment either operand is
+/// `None`, so composite indicators don't have to special-case their warm-up regions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series(Vec<Option<f64>>);
+
+impl Series {
+    /// Wraps a raw `Vec<Option<f64>>` directly.
+    pub fn new(values: Vec<Option<f64>>) -> Self {
+        Self(values)
+    }
+
+    /// Builds a `Series` from an indicator's output, left-padding with `None` so the
+    /// series lines up against the original (pre-warm-up) input length.
+    ///
+    /// # Arguments
+    /// - `values`: The indicator's computed output (already trimmed of its warm-up period).
+    /// - `total_len`: The length of the original input data the indicator was computed over.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tarq::series::Series;
+    ///
+    /// let ema_values = vec![2.0, 2.5, 3.0];
+    /// let series = Series::from_warmup(ema_values, 5);
+    ///
+    /// assert_eq!(series.values(), &[None, None, Some(2.0), Some(2.5), Some(3.0)]);
+    /// ```
+    pub fn from_warmup(values: Vec<f64>, total_len: usize) -> Self {
+        let pad = total_len.saturating_sub(values.len());
+        let mut out = Vec::with_capacity(total_len);
+        out.resize(pad, None);
+        out.extend(values.into_iter().map(Some));
+        Self(out)
+    }
+
+    /// Returns the number of positions in the series (including `None` entries).
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the series has no positions at all.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the underlying `Option<f64>` values.
+    pub fn values(&self) -> &[Option<f64>] {
+        &self.0
+    }
+
+    /// Shifts the series by `n` positions, filling the vacated positions with `None`.
+    ///
+    /// A positive `n` shifts values forward (toward later indices, as in a lag); a
+    /// negative `n` shifts them backward. The series length is unchanged.
+    pub fn shift(&self, n: isize) -> Self {
+        let len = self.0.len();
+
+        let out = if n >= 0 {
+            let n = n as usize;
+            (0..len).map(|i| if i < n { None } else { self.0[i - n] }).collect()
+        } else {
+            let n = (-n) as usize;
+            (0..len).map(|i| self.0.get(i + n).copied().flatten()).collect()
+        };
+
+        Self(out)
+    }
+
+    /// Returns the element-wise absolute value, propagating `None`.
+    pub fn abs(&self) -> Self {
+        Self(self.0.iter().map(|v| v.map(f64::abs)).collect())
+    }
+
+    /// Returns the element-wise maximum against `other`, propagating `None` when either
+    /// operand is `None`. Series are compared up to their common (shorter) length.
+    pub fn max(&self, other: &Series) -> Self {
+        Self::zip_with(self, other, f64::max)
+    }
+
+    /// Returns the element-wise minimum against `other`, propagating `None` when either
+    /// operand is `None`. Series are compared up to their common (shorter) length.
+    pub fn min(&self, other: &Series) -> Self {
+        Self::zip_with(self, other, f64::min)
+    }
+
+    /// Returns the element-wise sum with `other`, propagating `None` when either operand
+    /// is `None`. Series are combined up to their common (shorter) length.
+    pub fn add(&self, other: &Series) -> Self {
+        Self::zip_with(self, other, |a, b| a + b)
+    }
+
+    /// Returns the element-wise difference with `other`, propagating `None` when either
+    /// operand is `None`. Series are combined up to their common (shorter) length.
+    pub fn sub(&self, other: &Series) -> Self {
+        Self::zip_with(self, other, |a, b| a - b)
+    }
+
+    /// Returns the element-wise product with `other`, propagating `None` when either
+    /// operand is `None`. Series are combined up to their common (shorter) length.
+    pub fn mul(&self, other: &Series) -> Self {
+        Self::zip_with(self, other, |a, b| a * b)
+    }
+
+    /// Returns the element-wise quotient with `other`, propagating `None` when either
+    /// operand is `None` or the divisor is `0.0`. Series are combined up to their common
+    /// (shorter) length.
+    pub fn div(&self, other: &Series) -> Self {
+        let len = self.0.len().min(other.0.len());
+        let values = (0..len)
+            .map(|i| match (self.0[i], other.0[i]) {
+                (Some(a), Some(b)) if b != 0.0 => Some(a / b),
+                _ => None,
+            })
+            .collect();
+
+        Self(values)
+    }
+
+    /// Fills every `None` position with `fill` and unwraps the series into a plain `Vec<f64>`.
+    pub fn into_vec(self, fill: f64) -> Vec<f64> {
+        self.0.into_iter().map(|v| v.unwrap_or(fill)).collect()
+    }
+
+    /// Fills every `None` position with `f64::NAN` and unwraps the series into a plain
+    /// `Vec<f64>`. A convenience alias for [`Series::into_vec`] matching the NaN-padded
+    /// warm-up convention used at language-boundary outputs (e.g. the Python bindings).
+    pub fn into_nan_vec(self) -> Vec<f64> {
+        self.into_vec(f64::NAN)
+    }
+
+    /// Returns the rolling sum over a trailing window of `n` positions, propagating `None`
+    /// for any position that doesn't yet have `n` values behind it (including the gap) or
+    /// whose window contains a `None`.
+    pub fn rolling_sum(&self, n: usize) -> Self {
+        if n == 0 {
+            return Self(vec![None; self.0.len()]);
+        }
+
+        let values = (0..self.0.len())
+            .map(|i| {
+                if i + 1 < n {
+                    return None;
+                }
+
+                self.0[i + 1 - n..=i].iter().copied().sum::<Option<f64>>()
+            })
+            .collect();
+
+        Self(values)
+    }
+
+    fn zip_with(a: &Series, b: &Series, f: impl Fn(f64, f64) -> f64) -> Self {
+        let len = a.0.len().min(b.0.len());
+        let values = (0..len)
+            .map(|i| match (a.0[i], b.0[i]) {
+                (Some(x), Some(y)) => Some(f(x, y)),
+                _ => None,
+            })
+            .collect();
+
+        Self(values)
+    }
+}
+
+impl From<&[f64]> for Series {
+    /// Wraps every value in `Some`, with no warm-up gap.
+    fn from(values: &[f64]) -> Self {
+        Series(values.iter().map(|&v| Some(v)).collect())
+    }
+}
+
+impl std::ops::Add<&Series> for &Series {
+    type Output = Series;
+
+    /// Equivalent to [`Series::add`].
+    fn add(self, rhs: &Series) -> Series {
+        Series::add(self, rhs)
+    }
+}
+
+impl std::ops::Sub<&Series> for &Series {
+    type Output = Series;
+
+    /// Equivalent to [`Series::sub`].
+    fn sub(self, rhs: &Series) -> Series {
+        Series::sub(self, rhs)
+    }
+}
+
+impl std::ops::Mul<&Series> for &Series {
+    type Output = Series;
+
+    /// Equivalent to [`Series::mul`].
+    fn mul(self, rhs: &Series) -> Series {
+        Series::mul(self, rhs)
+    }
+}
+
+impl std::ops::Div<&Series> for &Series {
+    type Output = Series;
+
+    /// Equivalent to [`Series::div`].
+    fn div(self, rhs: &Series) -> Series {
+        Series::div(self, rhs)
+    }
+}
+
+impl std::ops::Add<f64> for &Series {
+    type Output = Series;
+
+    /// Adds `rhs` to every position, propagating `None`.
+    fn add(self, rhs: f64) -> Series {
+        Series(self.0.iter().map(|v| v.map(|x| x + rhs)).collect())
+    }
+}
+
+impl std::ops::Sub<f64> for &Series {
+    type Output = Series;
+
+    /// Subtracts `rhs` from every position, propagating `None`.
+    fn sub(self, rhs: f64) -> Series {
+        Series(self.0.iter().map(|v| v.map(|x| x - rhs)).collect())
+    }
+}
+
+impl std::ops::Mul<f64> for &Series {
+    type Output = Series;
+
+    /// Multiplies every position by `rhs`, propagating `None`.
+    fn mul(self, rhs: f64) -> Series {
+        Series(self.0.iter().map(|v| v.map(|x| x * rhs)).collect())
+    }
+}
+
+impl std::ops::Div<f64> for &Series {
+    type Output = Series;
+
+    /// Divides every position by `rhs`, propagating `None` (including when `rhs` is `0.0`).
+    fn div(self, rhs: f64) -> Series {
+        if rhs == 0.0 {
+            return Series(vec![None; self.0.len()]);
+        }
+
+        Series(self.0.iter().map(|v| v.map(|x| x / rhs)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_series_from_warmup_pads_leading_gap() {
+        let series = Series::from_warmup(vec![2.0, 2.5, 3.0], 5);
+        assert_eq!(series.values(), &[None, None, Some(2.0), Some(2.5), Some(3.0)]);
+    }
+
+    #[test]
+    fn test_series_arithmetic_propagates_none() {
+        let a = Series::from_warmup(vec![1.0, 2.0], 3);
+        let b = Series::from(&[10.0, 20.0, 30.0][..]);
+
+        let sum = a.add(&b);
+        assert_eq!(sum.values(), &[None, Some(21.0), Some(32.0)]);
+
+        let diff = a.sub(&b);
+        assert_eq!(diff.values(), &[None, Some(-19.0), Some(-28.0)]);
+
+        let product = a.mul(&b);
+        assert_eq!(product.values(), &[None, Some(20.0), Some(60.0)]);
+    }
+
+    #[test]
+    fn test_series_div_by_zero_is_none() {
+        let a = Series::from(&[10.0, 20.0][..]);
+        let b = Series::from(&[2.0, 0.0][..]);
+
+        let quotient = a.div(&b);
+        assert_eq!(quotient.values(), &[Some(5.0), None]);
+    }
+
+    #[test]
+    fn test_series_shift() {
+        let series = Series::from(&[1.0, 2.0, 3.0, 4.0][..]);
+
+        let forward = series.shift(2);
+        assert_eq!(forward.values(), &[None, None, Some(1.0), Some(2.0)]);
+
+        let backward = series.shift(-2);
+        assert_eq!(backward.values(), &[Some(3.0), Some(4.0), None, None]);
+    }
+
+    #[test]
+    fn test_series_abs_max_min() {
+        let a = Series::from(&[-3.0, 5.0][..]);
+        let b = Series::from(&[2.0, 1.0][..]);
+
+        assert_eq!(a.abs().values(), &[Some(3.0), Some(5.0)]);
+        assert_eq!(a.max(&b).values(), &[Some(2.0), Some(5.0)]);
+        assert_eq!(a.min(&b).values(), &[Some(-3.0), Some(1.0)]);
+    }
+
+    #[test]
+    fn test_series_into_vec_fills_none() {
+        let series = Series::from_warmup(vec![1.0, 2.0], 4);
+        assert_eq!(series.into_vec(0.0), vec![0.0, 0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_series_into_nan_vec_fills_with_nan() {
+        let series = Series::from_warmup(vec![1.0, 2.0], 4);
+        let values = series.into_nan_vec();
+
+        assert!(values[0].is_nan());
+        assert!(values[1].is_nan());
+        assert_eq!(&values[2..], &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_series_rolling_sum() {
+        let series = Series::from(&[1.0, 2.0, 3.0, 4.0][..]);
+        let sums = series.rolling_sum(2);
+
+        assert_eq!(sums.values(), &[None, Some(3.0), Some(5.0), Some(7.0)]);
+    }
+
+    #[test]
+    fn test_series_rolling_sum_propagates_none() {
+        let series = Series::from_warmup(vec![1.0, 2.0, 3.0], 4);
+        let sums = series.rolling_sum(3);
+
+        assert_eq!(sums.values(), &[None, None, None, Some(6.0)]);
+    }
+
+    #[test]
+    fn test_series_operator_overloads_match_methods() {
+        let a = Series::from_warmup(vec![1.0, 2.0], 3);
+        let b = Series::from(&[10.0, 20.0, 30.0][..]);
+
+        assert_eq!((&a + &b).values(), a.add(&b).values());
+        assert_eq!((&a - &b).values(), a.sub(&b).values());
+        assert_eq!((&a * &b).values(), a.mul(&b).values());
+        assert_eq!((&b / &a).values(), b.div(&a).values());
+    }
+
+    #[test]
+    fn test_series_scalar_operator_overloads() {
+        let series = Series::from_warmup(vec![1.0, 2.0], 3);
+
+        assert_eq!((&series + 1.0).values(), &[None, Some(2.0), Some(3.0)]);
+        assert_eq!((&series - 1.0).values(), &[None, Some(0.0), Some(1.0)]);
+        assert_eq!((&series * 2.0).values(), &[None, Some(2.0), Some(4.0)]);
+        assert_eq!((&series / 2.0).values(), &[None, Some(0.5), Some(1.0)]);
+        assert_eq!((&series / 0.0).values(), &[None, None, None]);
+    }
+}